@@ -1,8 +1,10 @@
 use hex_renderer::grids::GridDraw;
 use hex_renderer::grids::HexGrid;
 use hex_renderer::grids::SquareGrid;
+use hex_renderer::iota;
 use hex_renderer::pattern_utils;
 use hex_renderer::Pattern;
+use hex_renderer::PatternFormat;
 
 fn main() {
     //In order to draw a set of patterns, they need to be in a readable format first
@@ -27,6 +29,32 @@ fn main() {
         .collect::<Result<Vec<Pattern>, _>>()
         .expect("Invalid Pattern List!");
 
+    //patterns can also be turned back into text
+    //to_string gives the HexPattern(<starting_direction> <angle_sigs>) format
+    //and display gives the other formats (see PatternFormat)
+    println!("{}", patterns[0]);
+    println!("{}", patterns[0].display(PatternFormat::Short));
+
+    //the geometry of a pattern can be read back (path, points, segments, bounds, etc.)
+    //and stats sums it all up (eg. for sorting or filtering patterns)
+    println!("{:?}", patterns[0].stats());
+
+    //Numerical Reflections can be made straight from the number they push
+    //(the second argument is the longest signature allowed)
+    //and number_value reads the number back out of one
+    let number = Pattern::from_number(42.5, 30).expect("Number too long!");
+    println!("{number} pushes {:?}", number.number_value());
+
+    //Bookkeeper's Gambits work the same way with their masks (- keeps an iota and v removes it)
+    let bookkeeper = Pattern::from_bookkeeper_mask("v-v").expect("Invalid mask!");
+    println!("{bookkeeper} has the mask {:?}", bookkeeper.bookkeeper_mask());
+
+    //for whole spells that also have strings, numbers, vectors, etc. between the patterns
+    //iota::parse_iotas reads the full list (quoted strings can contain commas)
+    //and the grids have a new_iotas function to draw those non-pattern iotas as labelled tiles
+    let iotas = iota::parse_iotas(r#"HexPattern(WEST qqq), "Hello, world", 5, HexPattern(EAST eee)"#)
+        .expect("Invalid Iota List!");
+
     //alternatively, you can build the patterns by hand
     //angle sigs are mapped as followed:
     // w - Forward
@@ -89,6 +117,21 @@ fn main() {
     let max_width = 50;
     let hex_grid = HexGrid::new_normal(patterns, max_width).expect("Failed to make Hex Grid!");
 
+    //new_iotas works the same way, except it takes the parsed iotas from earlier
+    //along with how to draw their tiles (background, border, text and icon colors)
+    //with_captions adds a caption to each pattern from a closure (or a registry::PatternRegistry)
+    //any pattern the closure returns None for is left without a caption
+    let iota_grid = HexGrid::new_iotas(iotas, max_width, &hex_renderer::defaults::components::TILE)
+        .expect("Failed to make Hex Grid!")
+        .with_captions(
+            &|pattern: &Pattern| Some(pattern.display(PatternFormat::Short).to_string()),
+            &hex_renderer::defaults::CAPTION,
+        )
+        //with_numbers does the same for Numerical Reflections, labelling them with their value
+        .with_numbers(&hex_renderer::defaults::NUMBER_CAPTION)
+        //and with_bookkeeper_masks labels Bookkeeper's Gambits with their mask
+        .with_bookkeeper_masks(&hex_renderer::defaults::MASK_CAPTION);
+
     //for the square grid, max_width is how many tiles (patterns) long
     //each row is rather than the width of the tiles themselves
     let max_width = 10;
@@ -110,6 +153,12 @@ fn main() {
     //draw_grid -- simply draws the grid to a tiny_skia::Pixmap
     //draw_grid_png -- returns grid as a png represented by a vector of bytes
     //draw_grid_to_file -- saves the grid as a png to the provided file
+    //there are also svg versions for when you want vector output:
+    //draw_grid_svg -- returns the grid as an svg document (String)
+    //draw_grid_svg_to_file -- saves the grid as an svg to the provided file
+    //and animated versions that show the patterns being drawn one after another:
+    //draw_grid_animation -- returns the animation as a gif/apng represented by a vector of bytes
+    //draw_grid_animation_to_file -- saves the animation to the provided file
 
     //each of these types takes in the scale of the grid
     // + the patterns options talked about above
@@ -125,6 +174,29 @@ fn main() {
         .draw_grid_to_file("segment_hex_grid.png", scale, segment)
         .expect("Unable to write to file!");
 
+    hex_grid
+        .draw_grid_svg_to_file("segment_hex_grid.svg", scale, segment)
+        .expect("Unable to write to file!");
+
+    iota_grid
+        .draw_grid_to_file("iota_hex_grid.png", scale, segment)
+        .expect("Unable to write to file!");
+
+    //animations also take in AnimationOptions for the format, frame rate and timing
+    hex_grid
+        .draw_grid_animation_to_file(
+            "segment_hex_grid.gif",
+            scale,
+            segment,
+            &hex_renderer::defaults::ANIMATION,
+        )
+        .expect("Unable to write to file!");
+
+    //a single pattern can be animated on its own as well (the frames are sized to fit it)
+    number
+        .draw_animation_to_file("number.gif", scale, segment, &hex_renderer::defaults::ANIMATION)
+        .expect("Unable to write to file!");
+
     //for the square_grid, the scale is how many pixels tall/wide each tile (pattern) should be
     let scale = 200.0;
     square_grid
@@ -1,10 +1,10 @@
 use hex_renderer::{
     grids::{GridDraw, HexGrid},
     options::{
-        palettes, CollisionOption, EndPoint, GridOptions, GridPatternOptions, Intersections, Lines,
-        Marker, OverloadOptions, Point, Triangle, Color
+        palettes, CollisionOption, ColorSpace, Easing, EndPoint, GlowOptions, GridOptions, GridPatternOptions,
+        Intersections, Lines, Marker, OverloadOptions, Pigment, Point, Triangle, Color
     },
-    pattern_utils::Angle,
+    pattern_utils::{Angle, HexCoord},
     Pattern,
 };
 
@@ -101,6 +101,40 @@ fn main() {
         segments_per_color: 10,
         //whether to bend the lines at corners line in game
         bent: true,
+        //color space to blend between the colors in
+        //(Oklab keeps the middle of the gradient from looking muddy, Srgb is the default)
+        space: ColorSpace::Oklab,
+        //how fast it blends from one color to the next (eg. EaseInOut stays on each color longer)
+        easing: Easing::Linear,
+    };
+
+    //the pigment option colors the lines by where they are on the grid instead of along the pattern
+    //so neighbouring patterns flow into each other (like paint spread across the whole image)
+    //the field is measured in grid units (the distance between points) from the top left of the grid
+    // linear - bands of color along an angle (in degrees) repeating every period
+    // radial - rings of color around a center repeating every period
+    // noise - smooth random blotches about scale units wide (the seed picks the blotches)
+    let _pigment = Lines::Pigment {
+        colors: palettes::DEFAULT.to_vec(),
+        pigment: Pigment::Radial {
+            center: HexCoord(5.0, 5.0),
+            period: 10.0,
+        },
+        space: ColorSpace::Oklab,
+        bent: true,
+    };
+
+    //the zappy option draws jittery lines like lightning (the way they look on the staff grid ingame)
+    //it's drawn as a halo with a thinner core on top
+    //and the jitter comes from the seed, so the same seed always draws the same lines
+    let _zappy = Lines::Zappy {
+        core: Color::WHITE,
+        halo: Color(214, 9, 177, 255),
+        //how far (in relation to the distance between points) the lines can be pushed to the side
+        amplitude: 0.06,
+        //how many times the line zigzags between each pair of points
+        frequency: 4.0,
+        seed: 0,
     };
 
     //the segment option is by far the most complicated
@@ -346,6 +380,15 @@ fn main() {
         _overloaded_parallel_lines,
     );
 
+    //palettes::pigments has the pigments from the game (dyes, pride flags, etc.)
+    //they can be used the same way, or looked up by name (eg. from what a player typed)
+    //soulglimmer takes the player's UUID since every player has their own
+    let _pigment_gradient = GridPatternOptions::gen_changing_gradient(
+        _end_and_middle_points,
+        vec![palettes::pigments::by_name("Transgender Pigment").expect("Unknown pigment!")],
+        true,
+    );
+
     let _generator_options = GridOptions {
         line_thickness: 0.12,
         pattern_options: _changing_gradient,
@@ -356,6 +399,22 @@ fn main() {
         .draw_grid_to_file("generator_options_example.png", 50.0, &_generator_options)
         .expect("Failed to write to file!");
 
+    //for screenshots, the grid can also be given a glow that's drawn under the patterns
+    //(like how they look while being cast ingame) with any of the options
+    //the radius is how far it spreads past the lines (in relation to the distance between points)
+    //and it's blurred on the cpu, so it doesn't need a gpu to render
+    let _glow = GlowOptions {
+        radius: 0.3,
+        strength: 1.5,
+        //whether the points glow too
+        points: true,
+    };
+
+    hex_grid
+        .with_glow(&_glow)
+        .draw_grid_to_file("glowing_options_example.png", 50.0, &_generator_options)
+        .expect("Failed to write to file!");
+
     //in addition, if you want to use the defaults for some things,
     //all the parts to make the defaults are in hex_renderer::options::defaults
 }
@@ -0,0 +1,82 @@
+use hex_renderer::{
+    defaults,
+    grids::{GridDraw, HexGrid},
+    options::Color,
+    pattern_utils::HexCoord,
+    render::{LinePaint, LineStyle, RenderTarget, TextFont},
+    Pattern,
+};
+
+//all drawing goes through the RenderTarget trait
+//so to use your own backend (a web canvas, a recorder for tests, etc.)
+//you just need to implement it's four primitives
+
+//this one simply records what would have been drawn
+#[derive(Default)]
+struct Recorder {
+    commands: Vec<String>,
+}
+
+impl RenderTarget for Recorder {
+    fn stroke_path(&mut self, points: &[HexCoord], stroke: &LineStyle, _paint: &LinePaint) {
+        self.commands.push(format!(
+            "line through {} points with width {}",
+            points.len(),
+            stroke.width
+        ));
+    }
+
+    fn fill_circle(&mut self, center: HexCoord, radius: f32, _color: Color) {
+        self.commands
+            .push(format!("circle at ({}, {}) r={radius}", center.0, center.1));
+    }
+
+    fn fill_polygon(&mut self, points: &[HexCoord], _color: Color) {
+        self.commands
+            .push(format!("polygon with {} points", points.len()));
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        center: HexCoord,
+        size: f32,
+        _font: TextFont,
+        _color: Color,
+    ) {
+        self.commands.push(format!(
+            "text \"{text}\" at ({}, {}) size={size}",
+            center.0, center.1
+        ));
+    }
+}
+
+fn main() {
+    let patterns = vec![
+        Pattern::try_from("HexPattern(WEST qqq)").expect("Invalid Pattern!"),
+        Pattern::try_from("HexPattern(EAST eee)").expect("Invalid Pattern!"),
+    ];
+    let grid = HexGrid::new_normal(patterns, 50).expect("Failed to make grid!");
+
+    //draw_grid_on takes the target along with the usual scale and options
+    //plus the padding (as a percentage of scale) to put around the grid
+    let mut recorder = Recorder::default();
+    grid.draw_grid_on(&mut recorder, 50.0, &defaults::SEGMENT, 0.2)
+        .expect("Failed to draw grid!");
+
+    for command in recorder.commands {
+        println!("{command}");
+    }
+
+    //draw_grid_progress_on draws the grid partway through being drawn
+    //which is handy for rendering the frames of your own animations
+    let mut halfway = Recorder::default();
+    grid.draw_grid_progress_on(&mut halfway, 50.0, &defaults::SEGMENT, 0.2, 0.5)
+        .expect("Failed to draw grid!");
+    println!("halfway there: {} commands", halfway.commands.len());
+
+    //if your backend needs to know the image size ahead of time,
+    //get_padded_size gives the size (in pixels) for that scale and padding
+    let (width, height) = grid.get_padded_size(50.0, 0.2);
+    println!("image size: {width}x{height}");
+}
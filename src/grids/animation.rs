@@ -0,0 +1,385 @@
+use std::fs;
+
+use tiny_skia::Pixmap;
+
+use crate::{
+    options::{AnimationFormat, AnimationOptions, GridOptions},
+    pattern::PatternVariant,
+    pattern_utils::HexCoord,
+    Pattern,
+};
+
+use super::{draw_grid_on, GridContents, GridDrawError, GridFileError};
+
+///How far along the animation is in a single frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FrameState {
+    ///Number of patterns that are completely drawn
+    completed: usize,
+    ///Progress of the pattern currently being drawn (if it's started)
+    current: Option<f32>,
+}
+
+pub fn draw_animation(
+    contents: &GridContents,
+    size: (f32, f32),
+    scale: f32,
+    options: &GridOptions,
+    padding: f32,
+    animation: &AnimationOptions,
+) -> Result<Vec<u8>, GridDrawError> {
+    if scale < 1.0 {
+        return Err(GridDrawError::ImproperScale(scale));
+    }
+    if !(animation.frame_rate > 0.0 && animation.frame_rate.is_finite()) {
+        return Err(GridDrawError::ImproperFrameRate(animation.frame_rate));
+    }
+    for delay in [animation.pattern_delay, animation.hold_time] {
+        if !delay.is_finite() {
+            return Err(GridDrawError::ImproperDelay(delay));
+        }
+    }
+
+    let frames = split_long_frames(
+        get_frames(contents.patterns, animation),
+        max_frame_delay(animation.format),
+    );
+
+    let mut base =
+        Pixmap::new(size.0 as u32, size.1 as u32).ok_or(GridDrawError::ImproperScale(scale))?;
+    let mut base_completed = 0;
+
+    //with no patterns to wait on, the tiles are there from the start
+    if contents.patterns.is_empty() {
+        draw_grid_on(&mut base, contents, options, scale, padding, &|_| Some(1.0))?;
+    }
+
+    let mut data = Vec::new();
+    let mut writer = FrameWriter::new(&mut data, &base, animation.format, frames.len())?;
+
+    for (state, delay) in frames {
+        if state.completed > base_completed {
+            let finished = base_completed..state.completed;
+            draw_grid_on(&mut base, contents, options, scale, padding, &|index| {
+                finished.contains(&index).then_some(1.0)
+            })?;
+            base_completed = state.completed;
+        }
+
+        let mut frame = base.clone();
+        if let Some(progress) = state.current {
+            draw_grid_on(&mut frame, contents, options, scale, padding, &|index| {
+                (index == state.completed).then_some(progress)
+            })?;
+        }
+
+        writer.write_frame(&frame, delay)?;
+    }
+    writer.finish()?;
+
+    Ok(data)
+}
+
+///Works out what each frame should show and how long (in seconds) it's shown for
+fn get_frames(
+    patterns: &[(PatternVariant, HexCoord, f32)],
+    animation: &AnimationOptions,
+) -> Vec<(FrameState, f32)> {
+    let pattern_delay = animation.pattern_delay.max(0.0);
+
+    let mut timings = Vec::new();
+    let mut time = 0.0;
+    for (index, (pattern, _, _)) in patterns.iter().enumerate() {
+        let duration = animation
+            .pacing
+            .get_duration(index, pattern.get_inner().path.len() - 1);
+        timings.push((time, duration));
+        time += duration + pattern_delay;
+    }
+    let end = time - pattern_delay;
+
+    let state_at = |time: f32| {
+        let mut completed = 0;
+        let mut current = None;
+        for &(start, duration) in &timings {
+            if time >= start + duration {
+                completed += 1;
+            } else {
+                if time >= start {
+                    current = Some((time - start) / duration);
+                }
+                break;
+            }
+        }
+        FrameState { completed, current }
+    };
+
+    let frame_time = 1.0 / animation.frame_rate;
+    let frame_count = (end * animation.frame_rate).ceil() as usize;
+
+    let mut frames: Vec<(FrameState, f32)> = Vec::new();
+    for frame in 0..=frame_count {
+        let state = state_at((frame as f32 * frame_time).min(end));
+
+        match frames.last_mut() {
+            Some((last, delay)) if *last == state => *delay += frame_time,
+            _ => frames.push((state, frame_time)),
+        }
+    }
+    if let Some((_, delay)) = frames.last_mut() {
+        *delay += animation.hold_time.max(0.0);
+    }
+
+    frames
+}
+
+///Longest (in seconds) a single frame can be shown for in the format
+/// (gif delays are in hundredths of a second and apng delays are in thousandths, both stored in a u16)
+fn max_frame_delay(format: AnimationFormat) -> f32 {
+    match format {
+        AnimationFormat::Gif => u16::MAX as f32 / 100.0,
+        AnimationFormat::Apng => u16::MAX as f32 / 1000.0,
+    }
+}
+
+///Splits any frame that's shown for longer than max_delay into repeats of the same frame
+/// so long holds aren't cut short
+fn split_long_frames(frames: Vec<(FrameState, f32)>, max_delay: f32) -> Vec<(FrameState, f32)> {
+    let mut split = Vec::new();
+    for (state, mut delay) in frames {
+        while delay > max_delay {
+            split.push((state, max_delay));
+            delay -= max_delay;
+        }
+        split.push((state, delay));
+    }
+    split
+}
+
+impl Pattern {
+    ///Renders the pattern on its own as an animation of it being drawn
+    /// The frames are sized to fit the pattern (with the same padding the grids use)
+    /// returned as a vector of bytes in the format specified by the [AnimationOptions]
+    /// * scale - Distance (in pixels) between points
+    /// * options - [GridOptions] for rendering the pattern
+    /// * animation - [AnimationOptions] for the timing and format of the animation
+    pub fn draw_animation(
+        &self,
+        scale: f32,
+        options: &GridOptions,
+        animation: &AnimationOptions,
+    ) -> Result<Vec<u8>, GridDrawError> {
+        let padding = options.get_max_radius() * 1.1;
+        let bounds = self.bottom_right_bound - self.top_left_bound;
+        let size = (
+            (bounds.0 + padding * 2.0) * scale,
+            (bounds.1 + padding * 2.0) * scale,
+        );
+
+        let patterns = [(
+            PatternVariant::Normal(self.clone()),
+            HexCoord(0.0, 0.0) - self.top_left_bound,
+            1.0,
+        )];
+        let contents = GridContents {
+            patterns: &patterns,
+            tiles: &[],
+            captions: &[],
+            glow: None,
+        };
+
+        draw_animation(&contents, size, scale, options, padding, animation)
+    }
+
+    ///Renders the pattern on its own as an animation of it being drawn and saves it to the given file
+    /// * file_name - Name of the file to save the animation as
+    /// * scale - Distance (in pixels) between points
+    /// * options - [GridOptions] for rendering the pattern
+    /// * animation - [AnimationOptions] for the timing and format of the animation
+    pub fn draw_animation_to_file(
+        &self,
+        file_name: &str,
+        scale: f32,
+        options: &GridOptions,
+        animation: &AnimationOptions,
+    ) -> Result<(), GridFileError> {
+        fs::write(
+            file_name,
+            self.draw_animation(scale, options, animation)
+                .map_err(GridFileError::DrawError)?,
+        )
+        .map_err(GridFileError::SaveError)
+    }
+}
+
+enum FrameWriter<'a> {
+    Gif(gif::Encoder<&'a mut Vec<u8>>),
+    Apng(png::Writer<&'a mut Vec<u8>>),
+}
+
+impl<'a> FrameWriter<'a> {
+    fn new(
+        data: &'a mut Vec<u8>,
+        pixmap: &Pixmap,
+        format: AnimationFormat,
+        frame_count: usize,
+    ) -> Result<Self, GridDrawError> {
+        match format {
+            AnimationFormat::Gif => {
+                let width = u16::try_from(pixmap.width()).map_err(|_| GridDrawError::EncodeError)?;
+                let height =
+                    u16::try_from(pixmap.height()).map_err(|_| GridDrawError::EncodeError)?;
+
+                let mut encoder = gif::Encoder::new(data, width, height, &[])
+                    .map_err(|_| GridDrawError::EncodeError)?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(|_| GridDrawError::EncodeError)?;
+
+                Ok(Self::Gif(encoder))
+            }
+            AnimationFormat::Apng => {
+                let mut encoder = png::Encoder::new(data, pixmap.width(), pixmap.height());
+                encoder.set_color(png::ColorType::Rgba);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder
+                    .set_animated(frame_count as u32, 0)
+                    .map_err(|_| GridDrawError::EncodeError)?;
+
+                let writer = encoder
+                    .write_header()
+                    .map_err(|_| GridDrawError::EncodeError)?;
+
+                Ok(Self::Apng(writer))
+            }
+        }
+    }
+
+    ///Adds a frame that's shown for delay seconds
+    fn write_frame(&mut self, pixmap: &Pixmap, delay: f32) -> Result<(), GridDrawError> {
+        let mut rgba: Vec<u8> = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+
+        match self {
+            Self::Gif(encoder) => {
+                let mut frame = gif::Frame::from_rgba_speed(
+                    pixmap.width() as u16,
+                    pixmap.height() as u16,
+                    &mut rgba,
+                    10,
+                );
+                frame.delay = (delay * 100.0).round().min(u16::MAX as f32) as u16;
+                frame.dispose = gif::DisposalMethod::Background;
+
+                encoder
+                    .write_frame(&frame)
+                    .map_err(|_| GridDrawError::EncodeError)
+            }
+            Self::Apng(writer) => {
+                let delay = (delay * 1000.0).round().min(u16::MAX as f32) as u16;
+                writer
+                    .set_frame_delay(delay, 1000)
+                    .map_err(|_| GridDrawError::EncodeError)?;
+                writer
+                    .write_image_data(&rgba)
+                    .map_err(|_| GridDrawError::EncodeError)
+            }
+        }
+    }
+
+    fn finish(self) -> Result<(), GridDrawError> {
+        match self {
+            //the gif is finished when the encoder is dropped
+            Self::Gif(_) => Ok(()),
+            Self::Apng(writer) => writer.finish().map_err(|_| GridDrawError::EncodeError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::options::Pacing;
+
+    use super::*;
+
+    fn animation(format: AnimationFormat, hold_time: f32) -> AnimationOptions {
+        AnimationOptions {
+            format,
+            frame_rate: 10.0,
+            pacing: Pacing::Pattern(1.0),
+            pattern_delay: 0.0,
+            hold_time,
+        }
+    }
+
+    #[test]
+    fn long_holds_are_split() {
+        let pattern = [(
+            PatternVariant::Normal(Pattern::try_from("EAST qaq").unwrap()),
+            HexCoord(0.0, 0.0),
+            1.0,
+        )];
+
+        for format in [AnimationFormat::Gif, AnimationFormat::Apng] {
+            let animation = animation(format, 1000.0);
+            let max_delay = max_frame_delay(format);
+            let unsplit = get_frames(&pattern, &animation);
+            let frames = split_long_frames(unsplit.clone(), max_delay);
+
+            let total = |frames: &[(FrameState, f32)]| frames.iter().map(|(_, delay)| delay).sum::<f32>();
+            assert!((total(&frames) - total(&unsplit)).abs() < 0.01);
+            assert!(frames.len() > unsplit.len());
+            assert!(frames.iter().all(|(_, delay)| *delay <= max_delay));
+
+            //everything past the drawing is the finished pattern
+            let finished = FrameState {
+                completed: 1,
+                current: None,
+            };
+            let held: f32 = frames
+                .iter()
+                .filter(|(state, _)| *state == finished)
+                .map(|(_, delay)| delay)
+                .sum();
+            assert!(held >= 1000.0, "{held}");
+        }
+    }
+
+    #[test]
+    fn pattern_animation_fits_pattern() {
+        let pattern = Pattern::try_from("EAST qaq").unwrap();
+        let options = &*crate::defaults::MONOCOLOR;
+
+        let data = pattern
+            .draw_animation(50.0, options, &animation(AnimationFormat::Apng, 100.0))
+            .unwrap();
+        let decoder = png::Decoder::new(&data[..]);
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+
+        let padding = options.get_max_radius() * 1.1 * 2.0;
+        let bounds = pattern.bottom_right_bound - pattern.top_left_bound;
+        assert_eq!(info.width, ((bounds.0 + padding) * 50.0) as u32);
+        assert_eq!(info.height, ((bounds.1 + padding) * 50.0) as u32);
+
+        let frames = info.animation_control.unwrap().num_frames;
+        assert!(frames > 2, "{frames}");
+    }
+
+    #[test]
+    fn improper_delays() {
+        let pattern = Pattern::try_from("EAST qaq").unwrap();
+        let result = pattern.draw_animation(
+            50.0,
+            &crate::defaults::MONOCOLOR,
+            &animation(AnimationFormat::Gif, f32::INFINITY),
+        );
+        assert!(matches!(result, Err(GridDrawError::ImproperDelay(_))));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    options::{CaptionOptions, CaptionPosition},
+    pattern::PatternVariant,
+    pattern_utils::HexCoord,
+    registry::NameSource,
+    render::{text_width, RenderTarget},
+};
+
+use super::IotaTile;
+
+///Space (as a multiple of the font size) reserved for each row of captions
+const CAPTION_SPACE: f32 = 1.2;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+///Text drawn above or below a pattern on a grid
+pub struct Caption {
+    ///Text of the caption (before being cut off to fit)
+    pub text: String,
+    ///Center of the caption (in grid units)
+    pub location: HexCoord,
+    ///Index of the pattern the caption belongs to
+    /// The caption is drawn once that pattern is finished
+    pub index: usize,
+    ///How to draw the caption
+    pub options: CaptionOptions,
+}
+
+///Moves each row of the grid down to make room for the new captions and then adds them
+/// Rows without any captions are left as they are
+/// Captions that were already added move along with their patterns and the new ones are put past them
+/// * patterns - Patterns on the grid (which get moved)
+/// * tiles - Tiles on the grid (which get moved)
+/// * captions - Captions already on the grid (which get moved and added to)
+/// * size - Size of the grid (which gets extended)
+/// * names - Where to get the text of each caption from
+/// * options - How to draw the captions
+pub(super) fn add_captions(
+    patterns: &mut [(PatternVariant, HexCoord, f32)],
+    tiles: &mut [IotaTile],
+    captions: &mut Vec<Caption>,
+    size: &mut HexCoord,
+    names: &dyn NameSource,
+    options: &CaptionOptions,
+) {
+    let space = options.size * CAPTION_SPACE;
+
+    let texts = patterns
+        .iter()
+        .map(|pattern| names.get_pattern_name(pattern.0.get_inner()))
+        .collect::<Vec<Option<String>>>();
+
+    let bounds = (0..patterns.len())
+        .map(|index| captioned_bounds(&patterns[index], index, captions))
+        .chain(tiles.iter().map(|tile| (tile.location.1, tile.location.1 + tile.size.1)))
+        .collect::<Vec<(f32, f32)>>();
+    let rows = get_row_tops(&bounds);
+    let get_row = |top: f32| rows.iter().filter(|row_top| **row_top <= top).count() - 1;
+
+    //only rows with at least one caption need space
+    let mut captioned_rows = vec![false; rows.len()];
+    for (text, (top, _)) in texts.iter().zip(&bounds) {
+        if text.is_some() {
+            captioned_rows[get_row(*top)] = true;
+        }
+    }
+
+    //every row gets moved down by the space for its own captions (if above) and the captions of the rows above it
+    let shift = |top: f32| {
+        let row = get_row(top);
+        let rows_above = captioned_rows[..row].iter().filter(|captioned| **captioned).count();
+        let own_row = options.position == CaptionPosition::Above && captioned_rows[row];
+        (rows_above + own_row as usize) as f32 * space
+    };
+
+    let pattern_shifts = bounds[..patterns.len()]
+        .iter()
+        .map(|(top, _)| shift(*top))
+        .collect::<Vec<f32>>();
+
+    for (pattern, pattern_shift) in patterns.iter_mut().zip(&pattern_shifts) {
+        pattern.1 .1 += pattern_shift;
+    }
+    for caption in captions.iter_mut() {
+        caption.location.1 += pattern_shifts[caption.index];
+    }
+    for tile in tiles.iter_mut() {
+        tile.location.1 += shift(tile.location.1);
+    }
+    size.1 += captioned_rows.iter().filter(|captioned| **captioned).count() as f32 * space;
+
+    let new_captions = texts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, text)| {
+            let text = text?;
+            let pattern = &patterns[index];
+
+            let (top, bottom) = captioned_bounds(pattern, index, captions);
+            let inner = pattern.0.get_inner();
+            let center_x = pattern.1 .0
+                + (inner.top_left_bound.0 + inner.bottom_right_bound.0) / 2.0 * pattern.2;
+
+            //keep captions on the edges of the grid from being cut off
+            let half_width = text_width(&text, options.size, options.font).min(options.max_width) / 2.0;
+            let center_x = center_x.max(half_width).min((size.0 - half_width).max(half_width));
+
+            let y = match options.position {
+                CaptionPosition::Above => top - space / 2.0,
+                CaptionPosition::Below => bottom + space / 2.0,
+            };
+
+            Some(Caption {
+                text,
+                location: HexCoord(center_x, y),
+                index,
+                options: *options,
+            })
+        })
+        .collect::<Vec<Caption>>();
+
+    captions.extend(new_captions);
+}
+
+///Top and bottom (in grid units) of the pattern including any captions it already has
+fn captioned_bounds(
+    pattern: &(PatternVariant, HexCoord, f32),
+    index: usize,
+    captions: &[Caption],
+) -> (f32, f32) {
+    captions
+        .iter()
+        .filter(|caption| caption.index == index)
+        .fold(pattern_bounds(pattern), |(top, bottom), caption| {
+            let half_space = caption.options.size * CAPTION_SPACE / 2.0;
+            (
+                top.min(caption.location.1 - half_space),
+                bottom.max(caption.location.1 + half_space),
+            )
+        })
+}
+
+///Top and bottom (in grid units) of the pattern
+fn pattern_bounds((pattern, location, scale): &(PatternVariant, HexCoord, f32)) -> (f32, f32) {
+    let inner = pattern.get_inner();
+    (
+        location.1 + inner.top_left_bound.1 * scale,
+        location.1 + inner.bottom_right_bound.1 * scale,
+    )
+}
+
+///Finds the top of every row on the grid
+/// Rows never overlap, so a new row starts whenever something starts below everything before it
+fn get_row_tops(bounds: &[(f32, f32)]) -> Vec<f32> {
+    let mut bounds = bounds.to_vec();
+    bounds.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut rows = Vec::new();
+    let mut row_bottom = f32::MIN;
+
+    for (top, bottom) in bounds {
+        if top > row_bottom + 0.01 {
+            rows.push(top);
+        }
+        row_bottom = row_bottom.max(bottom);
+    }
+
+    rows
+}
+
+///Draws the caption, cutting it off with an ellipsis if it's too wide
+/// * offset - Location (in pixels) of the top left of the grid
+/// * scale - Size (in pixels) of one grid unit
+pub(super) fn draw_caption(
+    target: &mut dyn RenderTarget,
+    caption: &Caption,
+    offset: HexCoord,
+    scale: f32,
+) {
+    let size = caption.options.size * scale;
+    let max_width = caption.options.max_width * scale;
+
+    let mut text = caption.text.clone();
+    if text_width(&text, size, caption.options.font) > max_width {
+        let mut chars = caption.text.chars().collect::<Vec<char>>();
+        loop {
+            chars.pop();
+            text = chars.iter().collect::<String>().trim_end().to_string() + "...";
+            if chars.is_empty() || text_width(&text, size, caption.options.font) <= max_width {
+                break;
+            }
+        }
+    }
+
+    target.draw_text(&text, offset + caption.location * scale, size, caption.options.font, caption.options.color);
+}
@@ -3,22 +3,29 @@
 //! 
 //! The drawing options are within the [GridDraw] trait.
 
-use tiny_skia::Pixmap;
-
 use crate::{
-    options::GridOptions,
+    iota::Iota,
+    options::{CaptionOptions, GlowOptions, TileOptions},
     pattern::PatternVariant,
     pattern_utils::{Coord, HexCoord},
+    registry::NameSource,
     Pattern,
 };
 
-use super::{GridCreationError, GridDraw, GridDrawError};
+use super::{
+    caption::add_captions,
+    tile::{extract_tiles, split_iotas},
+    Caption, GridCreationError, GridDraw, IotaTile,
+};
 
 
 #[derive(Debug, PartialEq, PartialOrd)]
 ///Creates a hexagonal grid where patterns are all rendered to fit on the grid.
 pub struct HexGrid {
     patterns: Vec<(PatternVariant, HexCoord, f32)>,
+    tiles: Vec<IotaTile>,
+    captions: Vec<Caption>,
+    glow: Option<GlowOptions>,
     bottom_right: HexCoord,
 }
 
@@ -33,10 +40,33 @@ impl HexGrid {
         )
     }
 
+    ///Creates a new [HexGrid] from a list of iotas
+    /// Anything that isn't a pattern (strings, numbers, etc.) is drawn as a labelled tile in its place
+    /// * iotas - Vec of [Iota] to put on the grid (in order)
+    /// * max_width - The width (in grid points) of the grid
+    /// * tile_options - [TileOptions] for how to draw the tiles (eg. [defaults::components::TILE](crate::defaults::components::TILE))
+    pub fn new_iotas(
+        iotas: Vec<Iota>,
+        max_width: usize,
+        tile_options: &TileOptions,
+    ) -> Result<Self, GridCreationError> {
+        let (patterns, iotas) = split_iotas(iotas);
+
+        let grid = Self::new(patterns, max_width)?;
+        let (patterns, tiles) = extract_tiles(grid.patterns, iotas, tile_options);
+
+        Ok(HexGrid {
+            patterns,
+            tiles,
+            captions: Vec::new(),
+            glow: None,
+            bottom_right: grid.bottom_right,
+        })
+    }
+
     ///Creates a new grid with [PatternVariant], allowing special cases (like great spells)
     /// * patterns - Vec of [PatternVariant] to align on the grid
     /// * max_width - The width (in grid points) of the grid
-    ///Creates a grid with a vector of PatternVariant for special cases (like great spells), max_widht is the distance (in pixels) between points on the grid.
     pub fn new(patterns: Vec<PatternVariant>, max_width: usize) -> Result<Self, GridCreationError> {
         if patterns.is_empty() {
             return Err(GridCreationError::EmptyPatternList);
@@ -169,17 +199,70 @@ impl HexGrid {
         }
         Ok(HexGrid {
             patterns: packed_patterns,
+            tiles: Vec::new(),
+            captions: Vec::new(),
+            glow: None,
             bottom_right: HexCoord(
                 max_x - left_offset.0,
                 HexCoord::get_y(current_y + max_y_row),
             ),
         })
     }
+
+    ///Adds a caption (such as the pattern's name) to each pattern, moving the rows apart to make room for them
+    /// Patterns without a name don't get a caption
+    /// * names - Where to get the captions from ([PatternRegistry](crate::registry::PatternRegistry) or a closure of `Fn(&Pattern) -> Option<String>`)
+    /// * options - [CaptionOptions] for how to draw the captions
+    pub fn with_captions(mut self, names: &dyn NameSource, options: &CaptionOptions) -> Self {
+        add_captions(
+            &mut self.patterns,
+            &mut self.tiles,
+            &mut self.captions,
+            &mut self.bottom_right,
+            names,
+            options,
+        );
+        self
+    }
+
+    ///Labels each Numerical Reflection with the number it pushes (see [Pattern::number_value])
+    /// Works the same way as [Self::with_captions] (so it can be used alongside it)
+    /// * options - [CaptionOptions] for how to draw the numbers (eg. [defaults::NUMBER_CAPTION](crate::defaults::NUMBER_CAPTION))
+    pub fn with_numbers(self, options: &CaptionOptions) -> Self {
+        self.with_captions(
+            &|pattern: &Pattern| pattern.number_value().map(|value| value.to_string()),
+            options,
+        )
+    }
+
+    ///Labels each Bookkeeper's Gambit with its mask (see [Pattern::bookkeeper_mask])
+    /// Works the same way as [Self::with_captions] (so it can be used alongside it)
+    /// * options - [CaptionOptions] for how to draw the masks (eg. [defaults::MASK_CAPTION](crate::defaults::MASK_CAPTION))
+    pub fn with_bookkeeper_masks(self, options: &CaptionOptions) -> Self {
+        self.with_captions(&Pattern::bookkeeper_mask, options)
+    }
+
+    ///Draws a blurred glow under the patterns (tinted with the colors of the lines)
+    /// The padding around the grid grows to fit it
+    /// * glow - [GlowOptions] for how far and bright the glow is (eg. [defaults::components::GLOW](crate::defaults::components::GLOW))
+    pub fn with_glow(mut self, glow: &GlowOptions) -> Self {
+        self.glow = Some(*glow);
+        self
+    }
 }
 
 impl GridDraw for HexGrid {
-    fn draw_grid_with_padding(&self, scale: f32, options: &GridOptions, padding: f32) -> Result<Pixmap, GridDrawError> {
-        super::draw_grid_with_padding(self.bottom_right, &self.patterns, options, scale, padding)
+    fn get_patterns(&self) -> &[(PatternVariant, HexCoord, f32)] {
+        &self.patterns
+    }
+    fn get_tiles(&self) -> &[IotaTile] {
+        &self.tiles
+    }
+    fn get_captions(&self) -> &[Caption] {
+        &self.captions
+    }
+    fn get_glow(&self) -> Option<&GlowOptions> {
+        self.glow.as_ref()
     }
     fn get_unpadded_size(&self) -> (f32, f32) {
         (self.bottom_right.0, self.bottom_right.1)
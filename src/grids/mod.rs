@@ -10,14 +10,23 @@ pub use hex_grid::HexGrid;
 mod square_grid;
 pub use square_grid::SquareGrid;
 
+mod animation;
+
+mod tile;
+pub use tile::IotaTile;
+
+mod caption;
+pub use caption::Caption;
+
 use std::{collections::HashSet, fs, io};
 
 use tiny_skia::Pixmap;
 
 use crate::{
-    options::{GridOptions, GridPatternOptions, Intersections, Lines},
+    options::{AnimationOptions, Color, GlowOptions, GridOptions, GridPatternOptions, Intersections, Lines, Point},
     pattern::PatternVariant,
     pattern_utils::HexCoord,
+    render::{RenderTarget, SvgCanvas},
 };
 
 #[derive(Debug)]
@@ -35,6 +44,9 @@ pub enum GridFileError {
 #[derive(Debug)]
 pub enum GridDrawError {
     ImproperScale(f32),
+    ImproperFrameRate(f32),
+    ///The pattern_delay or hold_time of the animation isn't a finite number
+    ImproperDelay(f32),
     EncodeError,
 }
 #[derive(Debug)]
@@ -46,17 +58,139 @@ pub enum GridCreationError {
 ///Set of function for drawing grids
 pub trait GridDraw {
 
+    ///Patterns on the grid along with their location and scale
+    /// The location and scale are in grid units, so they get multiplied by the scale when drawing
+    fn get_patterns(&self) -> &[(PatternVariant, HexCoord, f32)];
+
+    ///Non-pattern iotas on the grid (only for grids created from iotas)
+    fn get_tiles(&self) -> &[IotaTile] {
+        &[]
+    }
+
+    ///Captions drawn with the patterns (only for grids with captions added)
+    fn get_captions(&self) -> &[Caption] {
+        &[]
+    }
+
+    ///Glow drawn under the patterns (only for grids with a glow added)
+    fn get_glow(&self) -> Option<&GlowOptions> {
+        None
+    }
+
+    ///Furthest anything gets drawn past the points of the patterns (in relation to the scale)
+    /// Used for the automatic padding, so it includes the glow
+    /// * options - [GridOptions] for rendering the patterns
+    fn get_max_radius(&self, options: &GridOptions) -> f32 {
+        let glow = self
+            .get_glow()
+            .map_or(0.0, |glow| options.line_thickness / 2.0 + glow.radius);
+
+        options.get_max_radius().max(glow)
+    }
+
+    ///Draws the grid onto any [RenderTarget] with a given padding around it
+    /// The target should be at least [GridDraw::get_padded_size] pixels large
+    /// * target - [RenderTarget] to draw the grid onto
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering the patterns
+    /// * padding - Amount of padding around grid as a percentage of scale
+    fn draw_grid_on(
+        &self,
+        target: &mut dyn RenderTarget,
+        scale: f32,
+        options: &GridOptions,
+        padding: f32,
+    ) -> Result<(), GridDrawError> {
+        draw_grid_on(target, &GridContents::new(self), options, scale, padding, &|_| Some(1.0))
+    }
+
+    ///Draws part of each pattern on the grid onto any [RenderTarget]
+    /// For driving your own animations, where each pattern can be at a different stage
+    /// * target - [RenderTarget] to draw the grid onto
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering the patterns
+    /// * padding - Amount of padding around grid as a percentage of scale
+    /// * progress - How much of the pattern at each index to draw (0-1), or None to skip it
+    fn draw_grid_partial_on(
+        &self,
+        target: &mut dyn RenderTarget,
+        scale: f32,
+        options: &GridOptions,
+        padding: f32,
+        progress: &dyn Fn(usize) -> Option<f32>,
+    ) -> Result<(), GridDrawError> {
+        draw_grid_on(target, &GridContents::new(self), options, scale, padding, progress)
+    }
+
+    ///Draws the grid onto any [RenderTarget] as it would look partway through being drawn
+    /// The patterns are drawn in order with every segment taking the same amount of time
+    /// * target - [RenderTarget] to draw the grid onto
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering the patterns
+    /// * padding - Amount of padding around grid as a percentage of scale
+    /// * progress - How much of the whole grid to draw (0 for nothing, 1 for the full grid)
+    fn draw_grid_progress_on(
+        &self,
+        target: &mut dyn RenderTarget,
+        scale: f32,
+        options: &GridOptions,
+        padding: f32,
+        progress: f32,
+    ) -> Result<(), GridDrawError> {
+        let progress = grid_progress(self.get_patterns(), progress);
+
+        draw_grid_on(target, &GridContents::new(self), options, scale, padding, &|index| progress[index])
+    }
+
+    ///Draws the grid as it would look partway through being drawn
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering patterns
+    /// * progress - How much of the whole grid to draw (0 for nothing, 1 for the full grid)
+    fn draw_grid_progress(&self, scale: f32, options: &GridOptions, progress: f32) -> Result<Pixmap, GridDrawError> {
+        let padding = self.get_max_radius(options) * 1.1;
+        let (width, height) = self.get_padded_size(scale, padding);
+
+        let mut pixmap = Pixmap::new(width as u32, height as u32)
+            .ok_or(GridDrawError::ImproperScale(scale))?;
+
+        self.draw_grid_progress_on(&mut pixmap, scale, options, padding, progress)?;
+
+        Ok(pixmap)
+    }
+
+    ///Size (in pixels) of the image created when drawing the grid with the given scale and padding
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * padding - Amount of padding around grid as a percentage of scale
+    fn get_padded_size(&self, scale: f32, padding: f32) -> (f32, f32) {
+        let size = self.get_unpadded_size();
+        let border_size = padding * scale;
+
+        (
+            border_size * 2.0 + size.0 * scale,
+            border_size * 2.0 + size.1 * scale,
+        )
+    }
+
     ///Draws the grid with a given padding around it
     /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
     /// * options - [GridOptions] for rendering the patterns
     /// * padding - Amount of padding around grid as a percentage of scale
-    fn draw_grid_with_padding(&self, scale: f32, options: &GridOptions, padding: f32) -> Result<Pixmap, GridDrawError>;
+    fn draw_grid_with_padding(&self, scale: f32, options: &GridOptions, padding: f32) -> Result<Pixmap, GridDrawError> {
+        let (width, height) = self.get_padded_size(scale, padding);
+
+        let mut pixmap = Pixmap::new(width as u32, height as u32)
+            .ok_or(GridDrawError::ImproperScale(scale))?;
+
+        self.draw_grid_on(&mut pixmap, scale, options, padding)?;
+
+        Ok(pixmap)
+    }
 
     ///Draws a grid with padding based on the options
     /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
     /// * options - [GridOptions] for rendering patterns
     fn draw_grid(&self, scale: f32, options: &GridOptions) -> Result<Pixmap, GridDrawError> {
-        let max_radius = options.get_max_radius();
+        let max_radius = self.get_max_radius(options);
 
         self.draw_grid_with_padding(scale, options, max_radius * 1.1)
     }
@@ -66,7 +200,7 @@ pub trait GridDraw {
 
     ///Size of grid with automatic padding based on [GridOptions]
     fn get_size(&self, options: &GridOptions) -> (f32, f32) {
-        let max_radius = options.get_max_radius();
+        let max_radius = self.get_max_radius(options);
 
         let size = self.get_unpadded_size();
         (max_radius * 2.0 * 1.1 + size.0, max_radius * 2.0 * 1.1 + size.1)
@@ -106,19 +240,216 @@ pub trait GridDraw {
         )
         .map_err(GridFileError::SaveError)
     }
+
+    ///Draws the grid as an svg with a given padding around it
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering the patterns
+    /// * padding - Amount of padding around grid as a percentage of scale
+    fn draw_grid_svg_with_padding(&self, scale: f32, options: &GridOptions, padding: f32) -> Result<String, GridDrawError> {
+        let (width, height) = self.get_padded_size(scale, padding);
+
+        let mut svg = SvgCanvas::new(width.floor(), height.floor());
+
+        self.draw_grid_on(&mut svg, scale, options, padding)?;
+
+        Ok(svg.finish())
+    }
+
+    ///Draws the grid as an svg (returned as a string) with padding based on the options
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering patterns
+    fn draw_grid_svg(&self, scale: f32, options: &GridOptions) -> Result<String, GridDrawError> {
+        let max_radius = self.get_max_radius(options);
+
+        self.draw_grid_svg_with_padding(scale, options, max_radius * 1.1)
+    }
+
+    ///Draws the grid as an svg and saves it to the given file
+    /// * file_name - Name of the file to save the image as
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering patterns
+    fn draw_grid_svg_to_file(
+        &self,
+        file_name: &str,
+        scale: f32,
+        options: &GridOptions,
+    ) -> Result<(), GridFileError> {
+        fs::write(
+            file_name,
+            self.draw_grid_svg(scale, options)
+                .map_err(GridFileError::DrawError)?,
+        )
+        .map_err(GridFileError::SaveError)
+    }
+
+    ///Renders the grid as an animation of each pattern being drawn in order
+    /// returned as a vector of bytes in the format specified by the [AnimationOptions]
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering patterns
+    /// * animation - [AnimationOptions] for the timing and format of the animation
+    fn draw_grid_animation(
+        &self,
+        scale: f32,
+        options: &GridOptions,
+        animation: &AnimationOptions,
+    ) -> Result<Vec<u8>, GridDrawError> {
+        let padding = self.get_max_radius(options) * 1.1;
+        let size = self.get_padded_size(scale, padding);
+
+        animation::draw_animation(&GridContents::new(self), size, scale, options, padding, animation)
+    }
+
+    ///Renders the grid as an animation of each pattern being drawn and saves it to the given file
+    /// * file_name - Name of the file to save the animation as
+    /// * scale - Size (in pixels) of width (distance between points for [HexGrid], tile_size for [SquareGrid])
+    /// * options - [GridOptions] for rendering patterns
+    /// * animation - [AnimationOptions] for the timing and format of the animation
+    fn draw_grid_animation_to_file(
+        &self,
+        file_name: &str,
+        scale: f32,
+        options: &GridOptions,
+        animation: &AnimationOptions,
+    ) -> Result<(), GridFileError> {
+        fs::write(
+            file_name,
+            self.draw_grid_animation(scale, options, animation)
+                .map_err(GridFileError::DrawError)?,
+        )
+        .map_err(GridFileError::SaveError)
+    }
 }
 
-fn draw_grid_with_padding(
-    size: HexCoord,
-    patterns: &Vec<(PatternVariant, HexCoord, f32)>,
+///Splits the progress of the whole grid into the progress of each pattern
+/// based on how many segments each pattern has
+fn grid_progress(patterns: &[(PatternVariant, HexCoord, f32)], progress: f32) -> Vec<Option<f32>> {
+    let total: usize = patterns
+        .iter()
+        .map(|(pattern, _, _)| pattern.get_inner().path.len() - 1)
+        .sum();
+
+    let mut remaining = progress.clamp(0.0, 1.0) * total as f32;
+
+    patterns
+        .iter()
+        .map(|(pattern, _, _)| {
+            let segments = (pattern.get_inner().path.len() - 1) as f32;
+
+            if remaining <= 0.0 {
+                None
+            } else {
+                let progress = (remaining / segments).min(1.0);
+                remaining -= segments;
+                Some(progress)
+            }
+        })
+        .collect()
+}
+
+///Everything on a grid that gets drawn
+struct GridContents<'a> {
+    patterns: &'a [(PatternVariant, HexCoord, f32)],
+    tiles: &'a [IotaTile],
+    captions: &'a [Caption],
+    glow: Option<&'a GlowOptions>,
+}
+
+impl<'a> GridContents<'a> {
+    fn new<T: GridDraw + ?Sized>(grid: &'a T) -> Self {
+        Self {
+            patterns: grid.get_patterns(),
+            tiles: grid.get_tiles(),
+            captions: grid.get_captions(),
+            glow: grid.get_glow(),
+        }
+    }
+}
+
+///Draws the patterns, tiles and captions onto the target
+/// progress gives how much of each pattern (by index) to draw (0-1), or None to skip it
+fn draw_grid_on(
+    target: &mut dyn RenderTarget,
+    contents: &GridContents,
     options: &GridOptions,
     scale: f32,
     padding: f32,
-) -> Result<Pixmap, GridDrawError> {
+    progress: &dyn Fn(usize) -> Option<f32>,
+) -> Result<(), GridDrawError> {
     if scale < 1.0 {
         return Err(GridDrawError::ImproperScale(scale));
     }
 
+    if let Some(glow) = contents.glow {
+        let lines_only;
+        let glow_options = if glow.points {
+            options
+        } else {
+            lines_only = without_points(options);
+            &lines_only
+        };
+        //the whole grid is blurred at once, so it spreads as far as it would for the biggest patterns
+        let pattern_scale = contents
+            .patterns
+            .iter()
+            .map(|(_, _, local_scale)| *local_scale)
+            .fold(0.0, f32::max);
+
+        target.draw_blurred(glow.radius * scale * pattern_scale, glow.strength, &mut |target| {
+            draw_patterns(target, contents.patterns, glow_options, scale, padding * scale, progress);
+        });
+    }
+
+    draw_patterns(target, contents.patterns, options, scale, padding * scale, progress);
+
+    let offset = HexCoord(padding * scale, padding * scale);
+    for tile in contents.tiles {
+        if tile::tile_visible(tile, contents.patterns.len(), progress) {
+            tile::draw_tile(target, tile, offset, scale, options.line_thickness);
+        }
+    }
+
+    for caption in contents.captions {
+        if progress(caption.index) == Some(1.0) {
+            caption::draw_caption(target, caption, offset, scale);
+        }
+    }
+
+    Ok(())
+}
+
+///Copy of the options that only draws the lines of the patterns (for glows that leave out the points)
+fn without_points(options: &GridOptions) -> GridOptions {
+    let pattern_options = match &options.pattern_options {
+        GridPatternOptions::Uniform(_, lines) => GridPatternOptions::Uniform(Intersections::Nothing, lines.clone()),
+        GridPatternOptions::Changing {
+            variations,
+            intros,
+            retros,
+        } => GridPatternOptions::Changing {
+            variations: variations
+                .iter()
+                .map(|(_, lines)| (Intersections::Nothing, lines.clone()))
+                .collect(),
+            intros: intros.clone(),
+            retros: retros.clone(),
+        },
+    };
+
+    GridOptions {
+        pattern_options,
+        center_dot: Point::None,
+        ..options.clone()
+    }
+}
+
+fn draw_patterns(
+    target: &mut dyn RenderTarget,
+    patterns: &[(PatternVariant, HexCoord, f32)],
+    options: &GridOptions,
+    scale: f32,
+    border_size: f32,
+    progress: &dyn Fn(usize) -> Option<f32>,
+) {
     let intersections;
     let lines;
     let intro_angles;
@@ -159,11 +490,9 @@ fn draw_grid_with_padding(
             bent: false,
             color: match line {
                 Lines::Monocolor { color, bent: _ } => *color,
-                Lines::Gradient {
-                    colors,
-                    segments_per_color: _,
-                    bent: _,
-                } => colors[0],
+                Lines::Gradient { colors, .. } => colors[0],
+                Lines::Pigment { colors, .. } => colors.first().copied().unwrap_or(Color::WHITE),
+                Lines::Zappy { core, .. } => *core,
                 Lines::SegmentColors {
                     colors,
                     triangles: _,
@@ -186,21 +515,13 @@ fn draw_grid_with_padding(
         })
         .collect::<Vec<Intersections>>();
 
-    let border_size = padding * scale;
-
     let offset = HexCoord(border_size, border_size);
 
-    let mut pixmap = Pixmap::new(
-        (border_size * 2.0 + size.0 * scale) as u32,
-        (border_size * 2.0 + size.1 * scale) as u32,
-    )
-    .unwrap();
-
     let mut lines_index = 0;
 
     let mut increment = false;
 
-    for (pattern, location, local_scale) in patterns {
+    for (index, (pattern, location, local_scale)) in patterns.iter().enumerate() {
         let location = *location * scale + offset;
 
         if intros.contains(&pattern.get_inner().angles) {
@@ -212,27 +533,32 @@ fn draw_grid_with_padding(
                 lines_index -= 1;
             }
         }
-        match pattern {
-            PatternVariant::Normal(pattern) => {
-                pattern.draw_pattern(
-                    &mut pixmap,
+        match (pattern, progress(index)) {
+            (_, None) => (),
+            (PatternVariant::Normal(pattern), Some(progress)) => {
+                pattern.draw_pattern_in_frame(
+                    target,
                     location,
                     scale * *local_scale,
+                    (offset, scale),
                     options.line_thickness,
                     lines[lines_index],
                     intersections[lines_index],
                     &options.center_dot,
+                    progress,
                 );
             }
-            PatternVariant::Monocolor(pattern) => {
-                pattern.draw_pattern(
-                    &mut pixmap,
+            (PatternVariant::Monocolor(pattern), Some(progress)) => {
+                pattern.draw_pattern_in_frame(
+                    target,
                     location,
                     scale * *local_scale,
+                    (offset, scale),
                     options.line_thickness,
                     &monocolor_lines[lines_index],
                     &monocolor_intersections[lines_index],
                     &options.center_dot,
+                    progress,
                 );
             }
         }
@@ -242,6 +568,4 @@ fn draw_grid_with_padding(
             lines_index = (lines_index + 1) % lines.len();
         }
     }
-
-    Ok(pixmap)
 }
@@ -3,18 +3,26 @@
 //! 
 //! Width of the grid is measured in tiles and it wraps around to the next line when going past that.
 
-use tiny_skia::Pixmap;
-
+use crate::iota::Iota;
+use crate::options::{CaptionOptions, GlowOptions, TileOptions};
 use crate::pattern::PatternVariant;
 use crate::pattern_utils::HexCoord;
-use crate::{options::GridOptions, Pattern};
+use crate::registry::NameSource;
+use crate::Pattern;
 
-use super::{GridCreationError, GridDraw, GridDrawError};
+use super::{
+    caption::add_captions,
+    tile::{extract_tiles, split_iotas},
+    Caption, GridCreationError, GridDraw, IotaTile,
+};
 
 #[derive(Debug, PartialEq, PartialOrd)]
 ///Grid of fixed size tiles where the patterns are automatically scaled to fit within.
 pub struct SquareGrid {
     patterns: Vec<(PatternVariant, HexCoord, f32)>,
+    tiles: Vec<IotaTile>,
+    captions: Vec<Caption>,
+    glow: Option<GlowOptions>,
     size: HexCoord,
 }
 
@@ -41,6 +49,36 @@ impl SquareGrid {
         )
     }
 
+    ///Creates a new SquareGrid from a list of iotas
+    /// Anything that isn't a pattern (strings, numbers, etc.) is drawn as a labelled tile in its place
+    /// * iotas - Vec of [Iota] to create the grid with (in order)
+    /// * max_width - Width of grid (in tiles)
+    /// * max_scale - Maximum scale to render the pattern as a percentage of the tile's length (in pixels)
+    /// * x_pad - Amount of padded space in the x direction (as a percentage of tile width)
+    /// * y_pad - Amount of padded space in the y direction (as a percetange of tile height)
+    /// * tile_options - [TileOptions] for how to draw the tiles (eg. [defaults::components::TILE](crate::defaults::components::TILE))
+    pub fn new_iotas(
+        iotas: Vec<Iota>,
+        max_width: usize,
+        max_scale: f32,
+        x_pad: f32,
+        y_pad: f32,
+        tile_options: &TileOptions,
+    ) -> Result<Self, GridCreationError> {
+        let (patterns, iotas) = split_iotas(iotas);
+
+        let grid = Self::new(patterns, max_width, max_scale, x_pad, y_pad)?;
+        let (patterns, tiles) = extract_tiles(grid.patterns, iotas, tile_options);
+
+        Ok(Self {
+            patterns,
+            tiles,
+            captions: Vec::new(),
+            glow: None,
+            size: grid.size,
+        })
+    }
+
     ///Creates a new SquareGrid with PatternVariant (which includes special cases such as great patterns)
     /// * patterns - Vec of PatternVariant to create the grid with
     /// * max_width - Width of grid (in tiles)
@@ -91,14 +129,67 @@ impl SquareGrid {
 
         Ok(Self {
             patterns: new_patterns,
+            tiles: Vec::new(),
+            captions: Vec::new(),
+            glow: None,
             size,
         })
     }
+
+    ///Adds a caption (such as the pattern's name) to each pattern, moving the rows apart to make room for them
+    /// Patterns without a name don't get a caption
+    /// * names - Where to get the captions from ([PatternRegistry](crate::registry::PatternRegistry) or a closure of `Fn(&Pattern) -> Option<String>`)
+    /// * options - [CaptionOptions] for how to draw the captions
+    pub fn with_captions(mut self, names: &dyn NameSource, options: &CaptionOptions) -> Self {
+        add_captions(
+            &mut self.patterns,
+            &mut self.tiles,
+            &mut self.captions,
+            &mut self.size,
+            names,
+            options,
+        );
+        self
+    }
+
+    ///Labels each Numerical Reflection with the number it pushes (see [Pattern::number_value])
+    /// Works the same way as [Self::with_captions] (so it can be used alongside it)
+    /// * options - [CaptionOptions] for how to draw the numbers (eg. [defaults::NUMBER_CAPTION](crate::defaults::NUMBER_CAPTION))
+    pub fn with_numbers(self, options: &CaptionOptions) -> Self {
+        self.with_captions(
+            &|pattern: &Pattern| pattern.number_value().map(|value| value.to_string()),
+            options,
+        )
+    }
+
+    ///Labels each Bookkeeper's Gambit with its mask (see [Pattern::bookkeeper_mask])
+    /// Works the same way as [Self::with_captions] (so it can be used alongside it)
+    /// * options - [CaptionOptions] for how to draw the masks (eg. [defaults::MASK_CAPTION](crate::defaults::MASK_CAPTION))
+    pub fn with_bookkeeper_masks(self, options: &CaptionOptions) -> Self {
+        self.with_captions(&Pattern::bookkeeper_mask, options)
+    }
+
+    ///Draws a blurred glow under the patterns (tinted with the colors of the lines)
+    /// The padding around the grid grows to fit it
+    /// * glow - [GlowOptions] for how far and bright the glow is (eg. [defaults::components::GLOW](crate::defaults::components::GLOW))
+    pub fn with_glow(mut self, glow: &GlowOptions) -> Self {
+        self.glow = Some(*glow);
+        self
+    }
 }
 
 impl GridDraw for SquareGrid {
-    fn draw_grid_with_padding(&self, scale: f32, options: &GridOptions, padding: f32) -> Result<Pixmap, GridDrawError> {
-        super::draw_grid_with_padding(self.size, &self.patterns, options, scale, padding)
+    fn get_patterns(&self) -> &[(PatternVariant, HexCoord, f32)] {
+        &self.patterns
+    }
+    fn get_tiles(&self) -> &[IotaTile] {
+        &self.tiles
+    }
+    fn get_captions(&self) -> &[Caption] {
+        &self.captions
+    }
+    fn get_glow(&self) -> Option<&GlowOptions> {
+        self.glow.as_ref()
     }
     fn get_unpadded_size(&self) -> (f32, f32) {
         (self.size.0, self.size.1)
@@ -0,0 +1,251 @@
+use std::f32::consts::PI;
+
+use crate::{
+    iota::Iota,
+    options::{Color, TileOptions},
+    pattern::PatternVariant,
+    pattern_utils::{Angle, Direction, HexCoord},
+    render::{fit_text, LineCap, LineJoin, LineStyle, LinePaint, RenderTarget, TextFont},
+    Pattern,
+};
+
+const MAX_LABEL_LENGTH: usize = 24;
+
+///Width (in grid points) of the placeholder, used to scale the lines to match the patterns
+const PLACEHOLDER_WIDTH: f32 = 4.0;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+///Non-pattern iota drawn as a labelled box on a grid
+pub struct IotaTile {
+    ///Iota shown on the tile
+    pub iota: Iota,
+    ///Top left corner of the tile (in grid units)
+    pub location: HexCoord,
+    ///Width and height of the tile (in grid units)
+    pub size: HexCoord,
+    ///Number of patterns that come before the tile
+    /// The tile is only drawn once all of those patterns are finished
+    pub index: usize,
+    ///How to draw the tile
+    pub options: TileOptions,
+}
+
+///Shape that takes up the place of a tile when laying out the grid
+/// (a hexagon with sides 2 points long)
+fn placeholder() -> Pattern {
+    let mut angles = Vec::new();
+    for _ in 0..5 {
+        angles.push(Angle::Forward);
+        angles.push(Angle::Right);
+    }
+    angles.push(Angle::Forward);
+
+    Pattern::new(Direction::East, angles)
+}
+
+///Replaces every non-pattern iota with a placeholder pattern so the grid can lay them out like any other pattern
+/// returns the patterns to lay out along with the iotas that were replaced (None for real patterns)
+pub(super) fn split_iotas(iotas: Vec<Iota>) -> (Vec<PatternVariant>, Vec<Option<Iota>>) {
+    iotas
+        .into_iter()
+        .map(|iota| match iota {
+            Iota::Pattern(pattern) => (PatternVariant::Normal(pattern), None),
+            iota => (PatternVariant::Normal(placeholder()), Some(iota)),
+        })
+        .unzip()
+}
+
+///Turns the laid out placeholders back into tiles
+/// returns the real patterns along with the tiles
+pub(super) fn extract_tiles(
+    patterns: Vec<(PatternVariant, HexCoord, f32)>,
+    iotas: Vec<Option<Iota>>,
+    options: &TileOptions,
+) -> (Vec<(PatternVariant, HexCoord, f32)>, Vec<IotaTile>) {
+    let mut real_patterns = Vec::new();
+    let mut tiles = Vec::new();
+
+    for ((pattern, location, scale), iota) in patterns.into_iter().zip(iotas) {
+        match iota {
+            None => real_patterns.push((pattern, location, scale)),
+            Some(iota) => {
+                let inner = pattern.get_inner();
+                tiles.push(IotaTile {
+                    iota,
+                    location: location + inner.top_left_bound * scale,
+                    size: (inner.bottom_right_bound - inner.top_left_bound) * scale,
+                    index: real_patterns.len(),
+                    options: *options,
+                });
+            }
+        }
+    }
+
+    (real_patterns, tiles)
+}
+
+///Whether a tile should be drawn based on the progress of the patterns
+/// (only once everything before it has been drawn)
+pub(super) fn tile_visible(
+    tile: &IotaTile,
+    pattern_count: usize,
+    progress: &dyn Fn(usize) -> Option<f32>,
+) -> bool {
+    if pattern_count == 0 {
+        true
+    } else if tile.index == 0 {
+        progress(0).is_some()
+    } else {
+        progress(tile.index - 1) == Some(1.0)
+    }
+}
+
+///Draws the tile as a box with an icon (for some types) and a label
+/// * offset - Location (in pixels) of the top left of the grid
+/// * scale - Size (in pixels) of one grid unit
+/// * line_thickness - Thickness of the pattern lines in relation to the distance between points
+pub(super) fn draw_tile(
+    target: &mut dyn RenderTarget,
+    tile: &IotaTile,
+    offset: HexCoord,
+    scale: f32,
+    line_thickness: f32,
+) {
+    //the border and icons are half as thick as the lines of a pattern the same size
+    let line_width = line_thickness * scale * tile.size.0 / PLACEHOLDER_WIDTH * 0.5;
+
+    let size = tile.size * scale * 0.9;
+    let top_left = offset + tile.location * scale + (tile.size * scale - size) / 2.0;
+    let bottom_right = top_left + size;
+
+    let corners = [
+        top_left,
+        HexCoord(bottom_right.0, top_left.1),
+        bottom_right,
+        HexCoord(top_left.0, bottom_right.1),
+    ];
+    target.fill_polygon(&corners, tile.options.background);
+
+    let border = LineStyle {
+        width: line_width,
+        cap: LineCap::Butt,
+        join: LineJoin::Miter,
+        dash: None,
+    };
+    let mut outline = corners.to_vec();
+    outline.push(corners[0]);
+    target.stroke_path(&outline, &border, &LinePaint::Solid(tile.options.border));
+
+    let center_x = top_left.0 + size.0 / 2.0;
+
+    let label = get_label(&tile.iota);
+    let icon_size = size.0.min(size.1) * 0.35;
+
+    let text_center = if draw_icon(
+        target,
+        &tile.iota,
+        HexCoord(center_x, top_left.1 + size.1 * 0.35),
+        icon_size,
+        line_width,
+        tile.options.icon,
+    ) {
+        HexCoord(center_x, top_left.1 + size.1 * 0.75)
+    } else {
+        HexCoord(center_x, top_left.1 + size.1 * 0.5)
+    };
+
+    let font_size = fit_text(&label, size.0 * 0.85, size.1 * 0.2, TextFont::Lato);
+    target.draw_text(&label, text_center, font_size, TextFont::Lato, tile.options.text);
+}
+
+///Text shown on the tile
+fn get_label(iota: &Iota) -> String {
+    let label = match iota {
+        Iota::Pattern(_) => "Pattern".to_string(),
+        Iota::String(str) => format!("\"{str}\""),
+        Iota::Number(number) => number.to_string(),
+        Iota::Vector(x, y, z) => format!("({x}, {y}, {z})"),
+        Iota::Boolean(true) => "True".to_string(),
+        Iota::Boolean(false) => "False".to_string(),
+        Iota::List(list) => format!("List ({})", list.len()),
+        Iota::Null => "Null".to_string(),
+        Iota::Garbage => "Garbage".to_string(),
+        Iota::Entity(name) => name.clone(),
+    };
+
+    if label.chars().count() > MAX_LABEL_LENGTH {
+        label.chars().take(MAX_LABEL_LENGTH - 3).collect::<String>() + "..."
+    } else {
+        label
+    }
+}
+
+///Draws a simple icon for the iota's type centered on the given point
+/// returns false if the type doesn't have an icon
+fn draw_icon(
+    target: &mut dyn RenderTarget,
+    iota: &Iota,
+    center: HexCoord,
+    size: f32,
+    line_width: f32,
+    color: Color,
+) -> bool {
+    let radius = size / 2.0;
+    let stroke = LineStyle::round(line_width);
+    let paint = LinePaint::Solid(color);
+
+    match iota {
+        //circle with a slash through it
+        Iota::Null => {
+            let circle = (0..=24)
+                .map(|i| {
+                    let angle = i as f32 / 24.0 * 2.0 * PI;
+                    center + HexCoord(angle.cos(), angle.sin()) * radius
+                })
+                .collect::<Vec<HexCoord>>();
+            target.stroke_path(&circle, &stroke, &paint);
+
+            let slash = HexCoord(radius, -radius) * 0.7;
+            target.stroke_path(&[center - slash, center + slash], &stroke, &paint);
+        }
+        //jagged line
+        Iota::Garbage => {
+            let zigzag = (0..=4)
+                .map(|i| {
+                    let y = if i % 2 == 0 { radius } else { -radius };
+                    center + HexCoord(radius * (i as f32 / 2.0 - 1.0), y * 0.6)
+                })
+                .collect::<Vec<HexCoord>>();
+            target.stroke_path(&zigzag, &stroke, &paint);
+        }
+        //head and shoulders
+        Iota::Entity(_) => {
+            target.fill_circle(center - HexCoord(0.0, radius * 0.45), radius * 0.4, color);
+            target.fill_polygon(
+                &[
+                    center + HexCoord(-radius * 0.7, radius),
+                    center + HexCoord(-radius * 0.45, radius * 0.1),
+                    center + HexCoord(radius * 0.45, radius * 0.1),
+                    center + HexCoord(radius * 0.7, radius),
+                ],
+                color,
+            );
+        }
+        //arrow pointing up and to the right
+        Iota::Vector(..) => {
+            let tip = center + HexCoord(radius, -radius) * 0.8;
+            target.stroke_path(&[center - HexCoord(radius, -radius) * 0.8, tip], &stroke, &paint);
+            target.fill_polygon(
+                &[
+                    tip + HexCoord(radius * 0.2, -radius * 0.2),
+                    tip - HexCoord(radius * 0.55, 0.0),
+                    tip + HexCoord(0.0, radius * 0.55),
+                ],
+                color,
+            );
+        }
+        _ => return false,
+    }
+
+    true
+}
@@ -0,0 +1,42 @@
+//! Iotas are the values that make up a spell (patterns along with strings, numbers, vectors, etc.)
+//!
+//! Use [parse_iotas] to read a whole spell dump such as
+//! `HexPattern(WEST qqq), Air, "No Matches Found", HexPattern(EAST eee), [1, 2]`
+
+mod parser;
+pub use parser::{parse_iotas, IotaParseError};
+
+use crate::Pattern;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+///Single value within a spell
+pub enum Iota {
+    ///Pattern to be cast
+    Pattern(Pattern),
+    ///Quoted string (with any escapes already removed)
+    String(String),
+    ///Number
+    Number(f64),
+    ///3D vector written as (x, y, z)
+    Vector(f64, f64, f64),
+    ///True or False
+    Boolean(bool),
+    ///Nested list of iotas
+    List(Vec<Iota>),
+    ///Null iota
+    Null,
+    ///Garbage iota
+    Garbage,
+    ///Anything else (entities, items, types, etc.) stored as its name
+    Entity(String),
+}
+
+impl Iota {
+    ///Gets the pattern if the iota is a pattern
+    pub fn as_pattern(&self) -> Option<&Pattern> {
+        match self {
+            Iota::Pattern(pattern) => Some(pattern),
+            _ => None,
+        }
+    }
+}
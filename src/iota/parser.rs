@@ -0,0 +1,372 @@
+use crate::{Pattern, PatternParseError};
+
+use super::Iota;
+
+#[derive(Debug, Clone)]
+pub enum IotaParseError {
+    ///A quoted string was never closed
+    UnterminatedString(String),
+    ///A bracket or parenthesis was never closed
+    UnclosedBracket(String),
+    ///A closing bracket or parenthesis without a matching opening one
+    UnexpectedBracket { input: String, bracket: char },
+    ///Nothing between two commas
+    EmptyIota(String),
+    ///Something was written after a closing quote
+    TrailingCharacters(String),
+    ///An iota starting with HexPattern( that isn't a valid pattern
+    InvalidPattern(PatternParseError),
+    ///An iota in parenthesis that isn't 3 numbers
+    InvalidVector(String),
+}
+
+///Parses a comma separated list of iotas (in order)
+/// Patterns have to be written as HexPattern(DIRECTION angles)
+/// Anything that isn't a recognised type (eg. Air, Chicken Type) is read as an [Iota::Entity]
+/// * input - List of iotas (eg. `HexPattern(WEST qqq), "Hi, there", [1, (0, 1, 0)]`)
+pub fn parse_iotas(input: &str) -> Result<Vec<Iota>, IotaParseError> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    split_top_level(input)?
+        .into_iter()
+        .map(|part| {
+            if part.trim().is_empty() {
+                Err(IotaParseError::EmptyIota(input.to_string()))
+            } else {
+                parse_iota(part)
+            }
+        })
+        .collect()
+}
+
+///Splits the input on every comma that isn't in a string or brackets
+fn split_top_level(input: &str) -> Result<Vec<&str>, IotaParseError> {
+    let mut parts = Vec::new();
+    let mut brackets = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+
+    while let Some(char) = input[index..].chars().next() {
+        let mut length = char.len_utf8();
+
+        match char {
+            '"' => {
+                length = read_string(&input[index..])
+                    .ok_or_else(|| IotaParseError::UnterminatedString(input.to_string()))?
+                    .1;
+            }
+            '(' => brackets.push(')'),
+            '[' => brackets.push(']'),
+            //the guard pops the bracket being closed, so only mismatches end up here
+            ')' | ']' if brackets.pop() != Some(char) => {
+                return Err(IotaParseError::UnexpectedBracket {
+                    input: input.to_string(),
+                    bracket: char,
+                });
+            }
+            ',' if brackets.is_empty() => {
+                parts.push(&input[start..index]);
+                start = index + 1;
+            }
+            _ => (),
+        }
+
+        index += length;
+    }
+
+    if !brackets.is_empty() {
+        return Err(IotaParseError::UnclosedBracket(input.to_string()));
+    }
+
+    parts.push(&input[start..]);
+
+    Ok(parts)
+}
+
+fn parse_iota(str: &str) -> Result<Iota, IotaParseError> {
+    let str = str.trim();
+
+    if str.starts_with('"') {
+        return parse_string(str).map(Iota::String);
+    }
+
+    if let Some(inner) = str.strip_prefix('[').and_then(|str| str.strip_suffix(']')) {
+        return parse_iotas(inner).map(Iota::List);
+    }
+
+    if let Some(inner) = str.strip_prefix('(').and_then(|str| str.strip_suffix(')')) {
+        return parse_vector(inner).ok_or_else(|| IotaParseError::InvalidVector(str.to_string()));
+    }
+
+    if str.to_lowercase().starts_with("hexpattern(") {
+        return Pattern::try_from(str)
+            .map(Iota::Pattern)
+            .map_err(IotaParseError::InvalidPattern);
+    }
+
+    //checking the first character keeps names like Infinity from being read as numbers
+    if str.starts_with(|char: char| char.is_ascii_digit() || "-+.".contains(char)) {
+        if let Ok(number) = str.parse::<f64>() {
+            return Ok(Iota::Number(number));
+        }
+    }
+
+    Ok(match &str.to_lowercase()[..] {
+        "null" => Iota::Null,
+        "garbage" => Iota::Garbage,
+        "true" => Iota::Boolean(true),
+        "false" => Iota::Boolean(false),
+        //only the HexPattern(...) form is a pattern, so names like East aren't read as one
+        _ => Iota::Entity(str.to_string()),
+    })
+}
+
+///Removes the quotes and escapes (\\ and \") from a string
+fn parse_string(str: &str) -> Result<String, IotaParseError> {
+    let (string, length) =
+        read_string(str).ok_or_else(|| IotaParseError::UnterminatedString(str.to_string()))?;
+
+    if str[length..].trim().is_empty() {
+        Ok(string)
+    } else {
+        Err(IotaParseError::TrailingCharacters(str.to_string()))
+    }
+}
+
+///Reads the quoted string at the start of the input
+/// returns the string (without the quotes and escapes) and its length in the input (including the quotes)
+/// or None if it's never closed
+/// Strings are dumped ingame without escaping anything, so a \ right before the closing quote
+/// (when the quote is followed by the end of the iota) is kept as a backslash instead of escaping the quote
+fn read_string(input: &str) -> Option<(String, usize)> {
+    let mut result = String::new();
+    let mut chars = input.char_indices().skip(1);
+
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => {
+                let (next_index, escaped) = chars.next()?;
+                if escaped == '"' && ends_iota(&input[next_index + 1..]) {
+                    result.push(char);
+                    return Some((result, next_index + 1));
+                }
+                result.push(escaped);
+            }
+            '"' => return Some((result, index + 1)),
+            _ => result.push(char),
+        }
+    }
+
+    None
+}
+
+///Whether the rest of the input starts with the end of an iota (a comma, closing bracket or nothing)
+fn ends_iota(rest: &str) -> bool {
+    let rest = rest.trim_start();
+    rest.is_empty() || rest.starts_with([',', ']', ')'])
+}
+
+fn parse_vector(str: &str) -> Option<Iota> {
+    let parts = str
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().ok())
+        .collect::<Option<Vec<f64>>>()?;
+
+    match parts[..] {
+        [x, y, z] => Some(Iota::Vector(x, y, z)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BENCHMARK_DUMP: &str = "HexPattern(EAST waqa), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST awdwaaww), HexPattern(EAST waqwwaqa), HexPattern(NORTH_EAST deddw), HexPattern(EAST ad), HexPattern(SOUTH_EAST awdwaaww), HexPattern(SOUTH_EAST awdwa), HexPattern(SOUTH_EAST awdd), HexPattern(NORTH_EAST waawaqwawqq), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST awdwaaww), HexPattern(EAST waqwwaqa), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST aqaawa), HexPattern(EAST aada), HexPattern(EAST aawdd), HexPattern(SOUTH_EAST aqaa), HexPattern(EAST aawdd), HexPattern(EAST aqwwaqwaad), HexPattern(EAST aawdd), HexPattern(SOUTH_EAST aqaaw), HexPattern(NORTH_EAST waaw), HexPattern(SOUTH_EAST aqaawww), HexPattern(WEST ddad), HexPattern(EAST aadaa), HexPattern(EAST waqaeaq), HexPattern(SOUTH_EAST aqaawww), HexPattern(WEST ddad), HexPattern(EAST aawdd), HexPattern(EAST aqwwaqwaad), HexPattern(EAST aawdd), HexPattern(EAST aadaa), HexPattern(EAST aqwaq), HexPattern(NORTH_EAST aw), HexPattern(SOUTH_EAST aqaaw), HexPattern(EAST aada), HexPattern(EAST aqwaq), HexPattern(SOUTH_EAST aqaaedwd), HexPattern(EAST aada), HexPattern(SOUTH_EAST aqaawa), HexPattern(SOUTH_WEST ewdqdwe), HexPattern(SOUTH_EAST aqaaw), HexPattern(SOUTH_EAST aqaawaa), HexPattern(WEST ddad), HexPattern(SOUTH_EAST aqaaq), HexPattern(WEST ddad), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST awdwa), HexPattern(EAST ad), HexPattern(EAST aawdd), HexPattern(SOUTH_EAST awdwaaww), HexPattern(EAST aawdd), HexPattern(NORTH_EAST waawaqwawqq), HexPattern(SOUTH_EAST awdwa), HexPattern(EAST aawdd), HexPattern(SOUTH_EAST awdd), HexPattern(NORTH_EAST waawaqwawqq), HexPattern(SOUTH_EAST aqaawa), HexPattern(SOUTH_WEST ewdqdwe), HexPattern(SOUTH_EAST awdd), HexPattern(NORTH_WEST qwaeawq), HexPattern(NORTH_EAST qqaeaae), HexPattern(NORTH_EAST dwqqqqqwddww), HexPattern(EAST aadaa), HexPattern(EAST aqaeaq), HexPattern(WEST qqq), HexPattern(SOUTH_WEST aaqwqaa), HexPattern(SOUTH_EAST aqaaedwd), HexPattern(WEST ddad), HexPattern(EAST aawdd), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST aweeeeewaaww), HexPattern(EAST aawdd), HexPattern(EAST wawqwawaw), HexPattern(NORTH_EAST dedq), HexPattern(WEST dwwdwwdwdd), HexPattern(WEST qqq), \"\\\", HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(EAST aawdd), HexPattern(NORTH_EAST waawaqwawqq), HexPattern(WEST qqq), \"/\", HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(NORTH_EAST waawaqwawqq), HexPattern(SOUTH_EAST aqaawaa), HexPattern(EAST aada), HexPattern(EAST waqwwaqa), HexPattern(NORTH_EAST deddw), HexPattern(EAST ad), HexPattern(WEST qqq), HexPattern(SOUTH_EAST a), HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(WEST qqq), HexPattern(SOUTH_WEST edqde), HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(SOUTH_EAST awdd), HexPattern(SOUTH_EAST deaqq), HexPattern(EAST aawdd), HexPattern(EAST eee), HexPattern(EAST aawdd), HexPattern(NORTH_EAST qeewdweddw), HexPattern(SOUTH_EAST aqaaedwd), HexPattern(WEST ddad), HexPattern(SOUTH_EAST aqaaedwd), HexPattern(WEST ddad), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST aqaaw), HexPattern(NORTH_WEST wddw), HexPattern(NORTH_WEST eqqwawqaaw), HexPattern(EAST aadaadaa), HexPattern(WEST qqq), HexPattern(NORTH_WEST qaeaq), HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(NORTH_EAST qeewdweddw), HexPattern(EAST aadaadaa), HexPattern(NORTH_EAST qeewdweddw), HexPattern(SOUTH_WEST ewdqdwe), HexPattern(SOUTH_EAST deaqq), HexPattern(EAST aawdd), HexPattern(NORTH_WEST eqqwawqaaw), HexPattern(SOUTH_EAST deaqq), HexPattern(SOUTH_EAST aeea), HexPattern(EAST aadaa), HexPattern(EAST aqaeaq), HexPattern(EAST aadaa), HexPattern(SOUTH_EAST aqaaw), HexPattern(EAST ad), HexPattern(WEST qqq), HexPattern(NORTH_WEST qwaeawq), HexPattern(SOUTH_WEST aaqwqaa), HexPattern(SOUTH_EAST ae), HexPattern(NORTH_EAST dedq), HexPattern(EAST eee), HexPattern(WEST qqq), HexPattern(NORTH_EAST de), HexPattern(WEST qqq), \"Too Many Matches Found!\", HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(NORTH_EAST de), HexPattern(SOUTH_EAST adada), HexPattern(SOUTH_EAST aqae), HexPattern(EAST eee), HexPattern(SOUTH_EAST awdd), HexPattern(EAST aawdd), HexPattern(SOUTH_EAST aqaa), HexPattern(EAST ad), HexPattern(EAST aawdd), HexPattern(WEST qqq), HexPattern(WEST qqq), \"No Matches Found\", HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(NORTH_EAST de), HexPattern(SOUTH_EAST adada), HexPattern(SOUTH_EAST aqae), HexPattern(EAST eee), HexPattern(EAST aawdd), HexPattern(SOUTH_EAST awdd), HexPattern(SOUTH_EAST deaqq), HexPattern(WEST qqq), HexPattern(SOUTH_WEST aqdee), HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(WEST qqq), HexPattern(EAST), HexPattern(EAST eee), HexPattern(NORTH_WEST qwaeawq), HexPattern(SOUTH_EAST awdd), HexPattern(SOUTH_EAST deaqq), HexPattern(EAST aawdd), HexPattern(EAST eaqaaw), HexPattern(NORTH_EAST qaq), HexPattern(SOUTH_WEST aa), HexPattern(EAST qded), HexPattern(SOUTH_EAST a)";
+
+    fn pattern(pattern: &str) -> Iota {
+        Iota::Pattern(Pattern::try_from(pattern).unwrap())
+    }
+
+    #[test]
+    fn spell_dump() {
+        let iotas = parse_iotas(r#"HexPattern(WEST qqq), Air, "No Matches Found", HexPattern(EAST eee), [1, 2]"#).unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                pattern("WEST qqq"),
+                Iota::Entity("Air".to_string()),
+                Iota::String("No Matches Found".to_string()),
+                pattern("EAST eee"),
+                Iota::List(vec![Iota::Number(1.0), Iota::Number(2.0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn entities_are_not_patterns() {
+        let iotas = parse_iotas("East, EAST qaq, NORTH_WEST, Chicken Type").unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::Entity("East".to_string()),
+                Iota::Entity("EAST qaq".to_string()),
+                Iota::Entity("NORTH_WEST".to_string()),
+                Iota::Entity("Chicken Type".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn patterns() {
+        let iotas = parse_iotas("HexPattern(EAST), hexpattern(SOUTH_EAST aqaaw)").unwrap();
+        assert_eq!(iotas, vec![pattern("EAST"), pattern("SOUTH_EAST aqaaw")]);
+
+        assert!(matches!(
+            parse_iotas("HexPattern(EAST x)"),
+            Err(IotaParseError::InvalidPattern(_))
+        ));
+    }
+
+    #[test]
+    fn quoted_commas() {
+        let iotas = parse_iotas(r#""Hello, world", "[1, (2", "a""#).unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::String("Hello, world".to_string()),
+                Iota::String("[1, (2".to_string()),
+                Iota::String("a".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        let iotas = parse_iotas(r#""\\", "say \"hi\" ok", "a \\\" b""#).unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::String("\\".to_string()),
+                Iota::String("say \"hi\" ok".to_string()),
+                Iota::String("a \\\" b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unescaped_backslash_before_closing_quote() {
+        //the way strings ending in a backslash are dumped ingame
+        let iotas = parse_iotas(r#""\", "/", ["\"], "end\""#).unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::String("\\".to_string()),
+                Iota::String("/".to_string()),
+                Iota::List(vec![Iota::String("\\".to_string())]),
+                Iota::String("end\\".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn benchmark_dump() {
+        //the spell used by the benchmark in lib.rs
+        let iotas = parse_iotas(BENCHMARK_DUMP).unwrap();
+
+        assert_eq!(iotas.len(), BENCHMARK_DUMP.matches("HexPattern(").count() + 4);
+        assert_eq!(
+            iotas
+                .iter()
+                .filter(|iota| !matches!(iota, Iota::Pattern(_)))
+                .collect::<Vec<_>>(),
+            vec![
+                &Iota::String("\\".to_string()),
+                &Iota::String("/".to_string()),
+                &Iota::String("Too Many Matches Found!".to_string()),
+                &Iota::String("No Matches Found".to_string()),
+            ]
+        );
+        assert_eq!(iotas[0], pattern("EAST waqa"));
+        assert_eq!(iotas.last(), Some(&pattern("SOUTH_EAST a")));
+    }
+
+    #[test]
+    fn nested_lists() {
+        let iotas = parse_iotas(r#"[], [1, [HexPattern(EAST qaq), "a, b"], [[Null]]], Garbage"#).unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::List(vec![]),
+                Iota::List(vec![
+                    Iota::Number(1.0),
+                    Iota::List(vec![pattern("EAST qaq"), Iota::String("a, b".to_string())]),
+                    Iota::List(vec![Iota::List(vec![Iota::Null])]),
+                ]),
+                Iota::Garbage,
+            ]
+        );
+    }
+
+    #[test]
+    fn vectors() {
+        let iotas = parse_iotas("(1, -2.5, 3), (0,0,0), [(1, 2, 3)]").unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::Vector(1.0, -2.5, 3.0),
+                Iota::Vector(0.0, 0.0, 0.0),
+                Iota::List(vec![Iota::Vector(1.0, 2.0, 3.0)]),
+            ]
+        );
+
+        assert!(matches!(parse_iotas("(1, 2)"), Err(IotaParseError::InvalidVector(_))));
+        assert!(matches!(parse_iotas("(1, a, 2)"), Err(IotaParseError::InvalidVector(_))));
+    }
+
+    #[test]
+    fn numbers_and_booleans() {
+        let iotas = parse_iotas("5, -0.25, +3, True, false, Infinity").unwrap();
+        assert_eq!(
+            iotas,
+            vec![
+                Iota::Number(5.0),
+                Iota::Number(-0.25),
+                Iota::Number(3.0),
+                Iota::Boolean(true),
+                Iota::Boolean(false),
+                Iota::Entity("Infinity".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_strings() {
+        assert!(matches!(
+            parse_iotas(r#"HexPattern(EAST qaq), "open"#),
+            Err(IotaParseError::UnterminatedString(_))
+        ));
+        //the escaped quote doesn't close the string since the iota doesn't end after it
+        assert!(matches!(
+            parse_iotas(r#""open\" more"#),
+            Err(IotaParseError::UnterminatedString(_))
+        ));
+    }
+
+    #[test]
+    fn other_errors() {
+        assert!(matches!(parse_iotas("[1, 2"), Err(IotaParseError::UnclosedBracket(_))));
+        assert!(matches!(
+            parse_iotas("1, 2]"),
+            Err(IotaParseError::UnexpectedBracket { bracket: ']', .. })
+        ));
+        assert!(matches!(parse_iotas("1, , 2"), Err(IotaParseError::EmptyIota(_))));
+        assert!(matches!(
+            parse_iotas(r#""a" b"#),
+            Err(IotaParseError::TrailingCharacters(_))
+        ));
+        assert_eq!(parse_iotas("  ").unwrap(), vec![]);
+    }
+}
@@ -15,9 +15,18 @@ pub use pattern::*;
 
 pub mod grids;
 
+pub mod iota;
+
 pub mod options;
 pub use options::defaults;
 
+pub mod registry;
+
+pub mod render;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 /*extern crate test;
 
 #[cfg(test)]
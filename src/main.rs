@@ -1,5 +1,6 @@
 use hex_renderer::{
     grids::{GridDraw, HexGrid},
+    iota::{parse_iotas, Iota},
     options::{self, GridOptions, Marker, Color},
     Pattern, PatternVariant,
 };
@@ -37,11 +38,15 @@ fn main() {
     //let patterns_str = "NORTH_EAST qaq, EAST aa, NORTH_EAST qaq, EAST wa, WEST qqq, SOUTH_EAST a, SOUTH_EAST wwwdwdwwwawqqeqwqqwqeqwqq, EAST eee, SOUTH_EAST aqaaeaqaa, NORTH_EAST wdwaw, NORTH_EAST dadad";
 
     // let patterns_str = "HexPattern(EAST sss)";
-    let patterns_str = "EAST wwwqq, EAST a, NORTH_WEST a, SOUTH_WEST a";
+    let patterns_str = "HexPattern(EAST wwwqq), HexPattern(EAST a), HexPattern(NORTH_WEST a), HexPattern(SOUTH_WEST a)";
 
-    let patterns: Vec<Pattern> = patterns_str
-        .split(", ")
-        .filter_map(|str| Pattern::try_from(str).ok())
+    let patterns: Vec<Pattern> = parse_iotas(patterns_str)
+        .expect("Invalid iotas!")
+        .into_iter()
+        .filter_map(|iota| match iota {
+            Iota::Pattern(pattern) => Some(pattern),
+            _ => None,
+        })
         .collect();
 
     let global_scale = 100.0;
@@ -121,7 +126,7 @@ fn main() {
                 collisions: options::CollisionOption::ParallelLines 
             }
         ),
-        center_dot: options::Point::None
+        center_dot: options::Point::None,
     };
 
     let grid = HexGrid::new(variants, 6).unwrap();
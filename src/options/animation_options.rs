@@ -0,0 +1,50 @@
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Options for rendering a grid as an animation of the patterns being drawn (in order)
+pub struct AnimationOptions {
+    ///File format of the animation
+    pub format: AnimationFormat,
+    ///Number of frames per second
+    pub frame_rate: f32,
+    ///How long it takes to draw each pattern
+    pub pacing: Pacing,
+    ///Pause (in seconds) after each pattern before starting the next one
+    pub pattern_delay: f32,
+    ///How long (in seconds) to show the finished grid before the animation loops
+    pub hold_time: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///File format of an animation
+pub enum AnimationFormat {
+    ///Animated gif (colors are reduced to a 256 color palette)
+    Gif,
+    ///Animated png
+    Apng,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///How long (in seconds) it takes to draw each pattern
+pub enum Pacing {
+    ///Every segment takes the same amount of time, so longer patterns take longer to draw
+    Segment(f32),
+    ///Every pattern takes the same amount of time, no matter how long it is
+    Pattern(f32),
+    ///Time to draw each pattern (in order)
+    /// If there are more patterns than times, the last time is used for the rest
+    Custom(Vec<f32>),
+}
+
+impl Pacing {
+    ///Time (in seconds) it takes to draw the pattern at the given index with the given amount of segments
+    pub fn get_duration(&self, index: usize, segments: usize) -> f32 {
+        let duration = match self {
+            Pacing::Segment(time) => *time * segments as f32,
+            Pacing::Pattern(time) => *time,
+            Pacing::Custom(times) => *times.get(index).or(times.last()).unwrap_or(&0.0),
+        };
+        duration.max(0.0)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::render::TextFont;
+
+use super::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Where to put a caption in relation to its pattern
+pub enum CaptionPosition {
+    ///Centered above the pattern
+    Above,
+    ///Centered below the pattern
+    Below,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Options for drawing captions (like pattern names) with each pattern on a grid
+pub struct CaptionOptions {
+    ///Whether the caption goes above or below the pattern
+    pub position: CaptionPosition,
+    ///Font size in relation to the scale of the grid
+    /// eg. for a HexGrid, 0.5 makes the text half as tall as the distance between points
+    pub size: f32,
+    ///Font to draw the text with
+    pub font: TextFont,
+    ///Color of the text
+    pub color: Color,
+    ///Maximum width of the caption in relation to the scale of the grid
+    /// Longer captions are cut off with an ellipsis (...)
+    pub max_width: f32,
+}
@@ -0,0 +1,639 @@
+use std::{fmt, str::FromStr};
+
+use super::{Color, ColorSpace};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    ///Starts with # but isn't 3, 4, 6 or 8 hex digits
+    InvalidHex(String),
+    ///Looks like rgb(...) or rgba(...) but the values inside aren't valid
+    InvalidFunction(String),
+    ///Not a hex color, rgb(...) or a CSS color name
+    UnknownName(String),
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    ///Reads a color written in any of the ways CSS allows for rgb colors:
+    /// * #RGB, #RGBA, #RRGGBB or #RRGGBBAA
+    /// * rgb(r, g, b) or rgba(r, g, b, a) where r, g and b are 0-255 (or percentages) and a is 0-1 (or a percentage)
+    /// * A CSS color name (eg. rebeccapurple or transparent)
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let str = str.trim();
+        let lower = str.to_lowercase();
+
+        if let Some(hex) = str.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ColorParseError::InvalidHex(str.to_string()));
+        }
+
+        if let Some(inner) = lower
+            .strip_prefix("rgba(")
+            .or_else(|| lower.strip_prefix("rgb("))
+            .and_then(|inner| inner.strip_suffix(')'))
+        {
+            return parse_rgb(inner).ok_or_else(|| ColorParseError::InvalidFunction(str.to_string()));
+        }
+
+        CSS_COLORS
+            .iter()
+            .find(|(name, _)| *name == lower)
+            .map(|(_, color)| *color)
+            .ok_or_else(|| ColorParseError::UnknownName(str.to_string()))
+    }
+}
+
+impl fmt::Display for Color {
+    ///Writes the color as #RRGGBB (or #RRGGBBAA if it isn't opaque)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Color(r, g, b, a) = self;
+        if *a == 255 {
+            write!(f, "#{r:02X}{g:02X}{b:02X}")
+        } else {
+            write!(f, "#{r:02X}{g:02X}{b:02X}{a:02X}")
+        }
+    }
+}
+
+impl Color {
+    ///Same color with a different alpha
+    pub fn with_alpha(self, alpha: u8) -> Self {
+        Color(self.0, self.1, self.2, alpha)
+    }
+
+    ///Blends the color towards the other one (keeping the alpha of both in mind)
+    /// * amount - How much of the other color to use (0 for this color, 1 for the other one)
+    pub fn mix(self, other: Color, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+
+        Color(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+            mix(self.3, other.3),
+        )
+    }
+
+    ///Makes the color lighter by adding to its HSL lightness
+    /// * amount - How much lighter to make it (0 to 1, where 1 always gives white)
+    pub fn lighten(self, amount: f32) -> Self {
+        let (hue, saturation, lightness) = self.to_hsl();
+        Color::from_hsl(hue, saturation, (lightness + amount).clamp(0.0, 1.0)).with_alpha(self.3)
+    }
+
+    ///Makes the color darker by taking away from its HSL lightness
+    /// * amount - How much darker to make it (0 to 1, where 1 always gives black)
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    ///Hue (0-360), saturation (0-1) and lightness (0-1) of the color (ignoring alpha)
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        (hue, saturation, lightness)
+    }
+
+    ///Opaque color from its hue (in degrees), saturation (0-1) and lightness (0-1)
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Color::from_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    ///Hue (0-360), saturation (0-1) and value (0-1) of the color (ignoring alpha)
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        (hue, saturation, max)
+    }
+
+    ///Opaque color from its hue (in degrees), saturation (0-1) and value (0-1)
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+
+        let chroma = value * saturation;
+        Color::from_chroma(hue, chroma, value - chroma)
+    }
+
+    ///Lightness (0-1), a (green to red) and b (blue to yellow) of the color in the OKLab color space
+    /// (ignoring alpha)
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let [r, g, b] = [self.0, self.1, self.2].map(|channel| to_linear(channel as f32 / 255.0));
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    ///Opaque color from its lightness (0-1), a and b in the OKLab color space
+    /// Colors outside of the sRGB range are clamped to it
+    pub fn from_oklab(lightness: f32, a: f32, b: f32) -> Self {
+        let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+        let [r, g, b] = [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+        .map(|channel| to_channel(from_linear(channel)));
+
+        Color(r, g, b, 255)
+    }
+
+    ///Hue (0-360) along with the largest and smallest of the r, g and b channels (0-1)
+    fn hue(self) -> (f32, f32, f32) {
+        let [r, g, b] = [self.0, self.1, self.2].map(|channel| channel as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue.rem_euclid(360.0), max, min)
+    }
+
+    ///Opaque color from its hue, chroma and the amount added to every channel (shared by HSL and HSV)
+    fn from_chroma(hue: f32, chroma: f32, added: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Color(to_channel(r + added), to_channel(g + added), to_channel(b + added), 255)
+    }
+}
+
+impl ColorSpace {
+    ///Blends between the colors in this color space (alpha is always blended linearly)
+    /// * amount - How much of the second color to use (0 for the first color, 1 for the second one)
+    pub fn mix(self, from: Color, to: Color, amount: f32) -> Color {
+        let amount = amount.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| a + (b - a) * amount;
+        let alpha = (lerp(from.3 as f32, to.3 as f32)).round() as u8;
+
+        match self {
+            ColorSpace::Srgb => from.mix(to, amount),
+            ColorSpace::LinearRgb => {
+                let linear = |channel: u8| to_linear(channel as f32 / 255.0);
+                let channel = |a: u8, b: u8| to_channel(from_linear(lerp(linear(a), linear(b))));
+
+                Color(
+                    channel(from.0, to.0),
+                    channel(from.1, to.1),
+                    channel(from.2, to.2),
+                    alpha,
+                )
+            }
+            ColorSpace::Oklab => {
+                let (from, to) = (from.to_oklab(), to.to_oklab());
+                Color::from_oklab(lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+                    .with_alpha(alpha)
+            }
+            ColorSpace::Hsv => {
+                let (mut from, mut to) = (from.to_hsv(), to.to_hsv());
+
+                //grays don't have a hue, so they take the hue of the other color
+                if from.1 == 0.0 {
+                    from.0 = to.0;
+                } else if to.1 == 0.0 {
+                    to.0 = from.0;
+                }
+
+                //goes the shortest way around the color wheel
+                let mut hue_change = (to.0 - from.0).rem_euclid(360.0);
+                if hue_change > 180.0 {
+                    hue_change -= 360.0;
+                }
+
+                Color::from_hsv(
+                    from.0 + hue_change * amount,
+                    lerp(from.1, to.1),
+                    lerp(from.2, to.2),
+                )
+                .with_alpha(alpha)
+            }
+        }
+    }
+}
+
+///Converts a 0-1 value into a 0-255 channel
+fn to_channel(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+///Converts an sRGB channel (0-1) into linear light
+fn to_linear(value: f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+///Converts linear light back into an sRGB channel (0-1)
+fn from_linear(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+///Reads the digits of a hex color (without the #)
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|char| char.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    //the short forms repeat each digit (so #F80 is #FF8800)
+    let digits = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|char| [char, char]).collect::<String>(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+
+    let channel = |index: usize| {
+        digits
+            .get(index * 2..index * 2 + 2)
+            .map_or(Some(255), |channel| u8::from_str_radix(channel, 16).ok())
+    };
+
+    Some(Color(channel(0)?, channel(1)?, channel(2)?, channel(3)?))
+}
+
+///Reads the values inside of rgb(...) or rgba(...)
+/// They can be separated by commas or spaces (with the alpha after a / like in CSS)
+fn parse_rgb(inner: &str) -> Option<Color> {
+    let values = inner
+        .split(|char: char| char == ',' || char == '/' || char.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<&str>>();
+
+    if !(values.len() == 3 || values.len() == 4) {
+        return None;
+    }
+
+    let channel = |value: &str| {
+        let channel = match value.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().ok()? / 100.0 * 255.0,
+            None => value.parse::<f32>().ok()?,
+        };
+        (0.0..=255.0).contains(&channel).then(|| channel.round() as u8)
+    };
+    let alpha = |value: &str| {
+        let alpha = match value.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+            None => value.parse::<f32>().ok()?,
+        };
+        (0.0..=1.0).contains(&alpha).then(|| to_channel(alpha))
+    };
+
+    Some(Color(
+        channel(values[0])?,
+        channel(values[1])?,
+        channel(values[2])?,
+        values.get(3).map_or(Some(255), |value| alpha(value))?,
+    ))
+}
+
+///Named colors from CSS
+const CSS_COLORS: [(&str, Color); 149] = [
+    ("aliceblue", Color(240, 248, 255, 255)),
+    ("antiquewhite", Color(250, 235, 215, 255)),
+    ("aqua", Color(0, 255, 255, 255)),
+    ("aquamarine", Color(127, 255, 212, 255)),
+    ("azure", Color(240, 255, 255, 255)),
+    ("beige", Color(245, 245, 220, 255)),
+    ("bisque", Color(255, 228, 196, 255)),
+    ("black", Color(0, 0, 0, 255)),
+    ("blanchedalmond", Color(255, 235, 205, 255)),
+    ("blue", Color(0, 0, 255, 255)),
+    ("blueviolet", Color(138, 43, 226, 255)),
+    ("brown", Color(165, 42, 42, 255)),
+    ("burlywood", Color(222, 184, 135, 255)),
+    ("cadetblue", Color(95, 158, 160, 255)),
+    ("chartreuse", Color(127, 255, 0, 255)),
+    ("chocolate", Color(210, 105, 30, 255)),
+    ("coral", Color(255, 127, 80, 255)),
+    ("cornflowerblue", Color(100, 149, 237, 255)),
+    ("cornsilk", Color(255, 248, 220, 255)),
+    ("crimson", Color(220, 20, 60, 255)),
+    ("cyan", Color(0, 255, 255, 255)),
+    ("darkblue", Color(0, 0, 139, 255)),
+    ("darkcyan", Color(0, 139, 139, 255)),
+    ("darkgoldenrod", Color(184, 134, 11, 255)),
+    ("darkgray", Color(169, 169, 169, 255)),
+    ("darkgreen", Color(0, 100, 0, 255)),
+    ("darkgrey", Color(169, 169, 169, 255)),
+    ("darkkhaki", Color(189, 183, 107, 255)),
+    ("darkmagenta", Color(139, 0, 139, 255)),
+    ("darkolivegreen", Color(85, 107, 47, 255)),
+    ("darkorange", Color(255, 140, 0, 255)),
+    ("darkorchid", Color(153, 50, 204, 255)),
+    ("darkred", Color(139, 0, 0, 255)),
+    ("darksalmon", Color(233, 150, 122, 255)),
+    ("darkseagreen", Color(143, 188, 143, 255)),
+    ("darkslateblue", Color(72, 61, 139, 255)),
+    ("darkslategray", Color(47, 79, 79, 255)),
+    ("darkslategrey", Color(47, 79, 79, 255)),
+    ("darkturquoise", Color(0, 206, 209, 255)),
+    ("darkviolet", Color(148, 0, 211, 255)),
+    ("deeppink", Color(255, 20, 147, 255)),
+    ("deepskyblue", Color(0, 191, 255, 255)),
+    ("dimgray", Color(105, 105, 105, 255)),
+    ("dimgrey", Color(105, 105, 105, 255)),
+    ("dodgerblue", Color(30, 144, 255, 255)),
+    ("firebrick", Color(178, 34, 34, 255)),
+    ("floralwhite", Color(255, 250, 240, 255)),
+    ("forestgreen", Color(34, 139, 34, 255)),
+    ("fuchsia", Color(255, 0, 255, 255)),
+    ("gainsboro", Color(220, 220, 220, 255)),
+    ("ghostwhite", Color(248, 248, 255, 255)),
+    ("gold", Color(255, 215, 0, 255)),
+    ("goldenrod", Color(218, 165, 32, 255)),
+    ("gray", Color(128, 128, 128, 255)),
+    ("green", Color(0, 128, 0, 255)),
+    ("greenyellow", Color(173, 255, 47, 255)),
+    ("grey", Color(128, 128, 128, 255)),
+    ("honeydew", Color(240, 255, 240, 255)),
+    ("hotpink", Color(255, 105, 180, 255)),
+    ("indianred", Color(205, 92, 92, 255)),
+    ("indigo", Color(75, 0, 130, 255)),
+    ("ivory", Color(255, 255, 240, 255)),
+    ("khaki", Color(240, 230, 140, 255)),
+    ("lavender", Color(230, 230, 250, 255)),
+    ("lavenderblush", Color(255, 240, 245, 255)),
+    ("lawngreen", Color(124, 252, 0, 255)),
+    ("lemonchiffon", Color(255, 250, 205, 255)),
+    ("lightblue", Color(173, 216, 230, 255)),
+    ("lightcoral", Color(240, 128, 128, 255)),
+    ("lightcyan", Color(224, 255, 255, 255)),
+    ("lightgoldenrodyellow", Color(250, 250, 210, 255)),
+    ("lightgray", Color(211, 211, 211, 255)),
+    ("lightgreen", Color(144, 238, 144, 255)),
+    ("lightgrey", Color(211, 211, 211, 255)),
+    ("lightpink", Color(255, 182, 193, 255)),
+    ("lightsalmon", Color(255, 160, 122, 255)),
+    ("lightseagreen", Color(32, 178, 170, 255)),
+    ("lightskyblue", Color(135, 206, 250, 255)),
+    ("lightslategray", Color(119, 136, 153, 255)),
+    ("lightslategrey", Color(119, 136, 153, 255)),
+    ("lightsteelblue", Color(176, 196, 222, 255)),
+    ("lightyellow", Color(255, 255, 224, 255)),
+    ("lime", Color(0, 255, 0, 255)),
+    ("limegreen", Color(50, 205, 50, 255)),
+    ("linen", Color(250, 240, 230, 255)),
+    ("magenta", Color(255, 0, 255, 255)),
+    ("maroon", Color(128, 0, 0, 255)),
+    ("mediumaquamarine", Color(102, 205, 170, 255)),
+    ("mediumblue", Color(0, 0, 205, 255)),
+    ("mediumorchid", Color(186, 85, 211, 255)),
+    ("mediumpurple", Color(147, 112, 219, 255)),
+    ("mediumseagreen", Color(60, 179, 113, 255)),
+    ("mediumslateblue", Color(123, 104, 238, 255)),
+    ("mediumspringgreen", Color(0, 250, 154, 255)),
+    ("mediumturquoise", Color(72, 209, 204, 255)),
+    ("mediumvioletred", Color(199, 21, 133, 255)),
+    ("midnightblue", Color(25, 25, 112, 255)),
+    ("mintcream", Color(245, 255, 250, 255)),
+    ("mistyrose", Color(255, 228, 225, 255)),
+    ("moccasin", Color(255, 228, 181, 255)),
+    ("navajowhite", Color(255, 222, 173, 255)),
+    ("navy", Color(0, 0, 128, 255)),
+    ("oldlace", Color(253, 245, 230, 255)),
+    ("olive", Color(128, 128, 0, 255)),
+    ("olivedrab", Color(107, 142, 35, 255)),
+    ("orange", Color(255, 165, 0, 255)),
+    ("orangered", Color(255, 69, 0, 255)),
+    ("orchid", Color(218, 112, 214, 255)),
+    ("palegoldenrod", Color(238, 232, 170, 255)),
+    ("palegreen", Color(152, 251, 152, 255)),
+    ("paleturquoise", Color(175, 238, 238, 255)),
+    ("palevioletred", Color(219, 112, 147, 255)),
+    ("papayawhip", Color(255, 239, 213, 255)),
+    ("peachpuff", Color(255, 218, 185, 255)),
+    ("peru", Color(205, 133, 63, 255)),
+    ("pink", Color(255, 192, 203, 255)),
+    ("plum", Color(221, 160, 221, 255)),
+    ("powderblue", Color(176, 224, 230, 255)),
+    ("purple", Color(128, 0, 128, 255)),
+    ("rebeccapurple", Color(102, 51, 153, 255)),
+    ("red", Color(255, 0, 0, 255)),
+    ("rosybrown", Color(188, 143, 143, 255)),
+    ("royalblue", Color(65, 105, 225, 255)),
+    ("saddlebrown", Color(139, 69, 19, 255)),
+    ("salmon", Color(250, 128, 114, 255)),
+    ("sandybrown", Color(244, 164, 96, 255)),
+    ("seagreen", Color(46, 139, 87, 255)),
+    ("seashell", Color(255, 245, 238, 255)),
+    ("sienna", Color(160, 82, 45, 255)),
+    ("silver", Color(192, 192, 192, 255)),
+    ("skyblue", Color(135, 206, 235, 255)),
+    ("slateblue", Color(106, 90, 205, 255)),
+    ("slategray", Color(112, 128, 144, 255)),
+    ("slategrey", Color(112, 128, 144, 255)),
+    ("snow", Color(255, 250, 250, 255)),
+    ("springgreen", Color(0, 255, 127, 255)),
+    ("steelblue", Color(70, 130, 180, 255)),
+    ("tan", Color(210, 180, 140, 255)),
+    ("teal", Color(0, 128, 128, 255)),
+    ("thistle", Color(216, 191, 216, 255)),
+    ("tomato", Color(255, 99, 71, 255)),
+    ("turquoise", Color(64, 224, 208, 255)),
+    ("violet", Color(238, 130, 238, 255)),
+    ("wheat", Color(245, 222, 179, 255)),
+    ("white", Color(255, 255, 255, 255)),
+    ("whitesmoke", Color(245, 245, 245, 255)),
+    ("yellow", Color(255, 255, 0, 255)),
+    ("yellowgreen", Color(154, 205, 50, 255)),
+    ("transparent", Color(0, 0, 0, 0)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(str: &str) -> Result<Color, ColorParseError> {
+        str.parse()
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse("#F80"), Ok(Color(255, 136, 0, 255)));
+        assert_eq!(parse("#f808"), Ok(Color(255, 136, 0, 136)));
+        assert_eq!(parse("#1A2b3C"), Ok(Color(26, 43, 60, 255)));
+        assert_eq!(parse("#1A2B3C80"), Ok(Color(26, 43, 60, 128)));
+        assert_eq!(parse("  #000000  "), Ok(Color(0, 0, 0, 255)));
+    }
+
+    #[test]
+    fn rgb_functions() {
+        assert_eq!(parse("rgb(255, 0, 10)"), Ok(Color(255, 0, 10, 255)));
+        assert_eq!(parse("RGB(1,2,3)"), Ok(Color(1, 2, 3, 255)));
+        assert_eq!(parse("rgba(255, 0, 10, 0.5)"), Ok(Color(255, 0, 10, 128)));
+        assert_eq!(parse("rgba(0, 0, 0, 0)"), Ok(Color(0, 0, 0, 0)));
+        assert_eq!(parse("rgb(100%, 50%, 0%)"), Ok(Color(255, 128, 0, 255)));
+        assert_eq!(parse("rgba(10, 20, 30, 25%)"), Ok(Color(10, 20, 30, 64)));
+        assert_eq!(parse("rgb(10 20 30 / 0.2)"), Ok(Color(10, 20, 30, 51)));
+    }
+
+    #[test]
+    fn css_names() {
+        assert_eq!(parse("rebeccapurple"), Ok(Color(102, 51, 153, 255)));
+        assert_eq!(parse("White"), Ok(Color(255, 255, 255, 255)));
+        assert_eq!(parse("TRANSPARENT"), Ok(Color(0, 0, 0, 0)));
+        assert_eq!(parse("aliceblue"), Ok(Color(240, 248, 255, 255)));
+        assert_eq!(parse("yellowgreen"), Ok(Color(154, 205, 50, 255)));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let colors = [
+            Color(0, 0, 0, 255),
+            Color(255, 255, 255, 255),
+            Color(26, 43, 60, 255),
+            Color(26, 43, 60, 128),
+            Color(1, 2, 3, 0),
+        ];
+        for color in colors {
+            assert_eq!(parse(&color.to_string()), Ok(color), "{color}");
+        }
+
+        assert_eq!(Color(255, 136, 0, 255).to_string(), "#FF8800");
+        assert_eq!(Color(255, 136, 0, 17).to_string(), "#FF880011");
+
+        for (name, color) in CSS_COLORS {
+            assert_eq!(parse(&color.to_string()), Ok(color), "{name}");
+        }
+    }
+
+    #[test]
+    fn rejected() {
+        for hex in ["#", "#12", "#12345", "#1234567", "#123456789", "#GGG", "#+2ab34ff", "#12 34 56"] {
+            assert_eq!(parse(hex), Err(ColorParseError::InvalidHex(hex.to_string())), "{hex}");
+        }
+
+        for function in [
+            "rgb()",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(256, 0, 0)",
+            "rgb(-1, 0, 0)",
+            "rgb(a, b, c)",
+            "rgba(0, 0, 0, 2)",
+            "rgb(0, 0, 101%)",
+        ] {
+            assert_eq!(
+                parse(function),
+                Err(ColorParseError::InvalidFunction(function.to_string())),
+                "{function}"
+            );
+        }
+
+        for name in ["", "notacolor", "rgb(1, 2, 3", "123456"] {
+            assert_eq!(parse(name), Err(ColorParseError::UnknownName(name.to_string())), "{name}");
+        }
+    }
+
+    const COLORS: [Color; 8] = [
+        Color(0, 0, 0, 255),
+        Color(255, 255, 255, 255),
+        Color(128, 128, 128, 255),
+        Color(255, 0, 0, 255),
+        Color(12, 200, 90, 255),
+        Color(30, 60, 250, 255),
+        Color(255, 136, 0, 255),
+        Color(102, 51, 153, 255),
+    ];
+
+    ///Whether every channel is within 1 of the other color (for rounding)
+    fn close(a: Color, b: Color) -> bool {
+        let near = |a: u8, b: u8| a.abs_diff(b) <= 1;
+        near(a.0, b.0) && near(a.1, b.1) && near(a.2, b.2) && a.3 == b.3
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        for color in COLORS {
+            let (hue, saturation, lightness) = color.to_hsl();
+            let back = Color::from_hsl(hue, saturation, lightness);
+            assert!(close(back, color), "{color:?} {back:?}");
+        }
+        assert_eq!(Color(255, 0, 0, 255).to_hsl(), (0.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        for color in COLORS {
+            let (hue, saturation, value) = color.to_hsv();
+            let back = Color::from_hsv(hue, saturation, value);
+            assert!(close(back, color), "{color:?} {back:?}");
+        }
+        assert_eq!(Color(0, 0, 255, 255).to_hsv(), (240.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        for color in COLORS {
+            let (lightness, a, b) = color.to_oklab();
+            let back = Color::from_oklab(lightness, a, b);
+            assert!(close(back, color), "{color:?} {back:?}");
+        }
+
+        let (lightness, a, b) = Color::WHITE.to_oklab();
+        assert!((lightness - 1.0).abs() < 0.001 && a.abs() < 0.001 && b.abs() < 0.001);
+    }
+
+    #[test]
+    fn mix_endpoints() {
+        let spaces = [ColorSpace::Srgb, ColorSpace::LinearRgb, ColorSpace::Oklab, ColorSpace::Hsv];
+        let pairs = [
+            (Color(255, 0, 0, 255), Color(0, 0, 255, 128)),
+            (Color(12, 200, 90, 0), Color(255, 255, 255, 255)),
+            (Color(128, 128, 128, 255), Color(255, 136, 0, 255)),
+        ];
+
+        for space in spaces {
+            for (from, to) in pairs {
+                assert!(close(space.mix(from, to, 0.0), from), "{space:?} {from:?}");
+                assert!(close(space.mix(from, to, 1.0), to), "{space:?} {to:?}");
+                //amounts past the ends are clamped
+                assert!(close(space.mix(from, to, -1.0), from), "{space:?} {from:?}");
+                assert!(close(space.mix(from, to, 2.0), to), "{space:?} {to:?}");
+            }
+        }
+    }
+}
@@ -2,8 +2,12 @@
 use lazy_static::lazy_static;
 
 use crate::options::{palettes, GridPatternOptions, Marker, Triangle, Color};
+use crate::render::TextFont;
 
-use super::{EndPoint, GridOptions, Intersections, Lines, Point};
+use super::{
+    AnimationFormat, AnimationOptions, CaptionOptions, CaptionPosition, ColorSpace, Easing,
+    EndPoint, GlowOptions, GridOptions, Intersections, Lines, Pacing, Pigment, Point, TileOptions,
+};
 
 #[allow(dead_code)]
 pub mod constants {
@@ -16,11 +20,27 @@ pub mod constants {
     pub const GRADIENT_INNER_RADIUS: f32 = 0.05;
     pub const GRADIENT_OUTER_RADIUS: f32 = 0.07;
 
+    pub const PIGMENT_SCALE: f32 = 3.0;
+
+    pub const ZAPPY_AMPLITUDE: f32 = 0.06;
+    pub const ZAPPY_FREQUENCY: f32 = 4.0;
+
+    pub const GLOW_RADIUS: f32 = 0.3;
+    pub const GLOW_STRENGTH: f32 = 1.5;
+
     pub const TRIANGLE_INNER_RADIUS: f32 = 0.16;
     pub const TRIANGLE_OUTER_RADIUS: f32 = 0.25;
     pub const COLLISION_LINE_COUNT: usize = 4;
 
     pub const CENTER_DOT_RADIUS: f32 = OUTER_RADIUS;
+
+    pub const FRAME_RATE: f32 = 20.0;
+    pub const SEGMENT_TIME: f32 = 0.1;
+    pub const PATTERN_DELAY: f32 = 0.2;
+    pub const HOLD_TIME: f32 = 2.0;
+
+    pub const CAPTION_SIZE: f32 = 0.5;
+    pub const CAPTION_MAX_WIDTH: f32 = 6.0;
 }
 use constants::*;
 pub mod components {
@@ -28,6 +48,15 @@ pub mod components {
 
     use super::*;
 
+    lazy_static! {
+        pub static ref TILE: TileOptions = TileOptions {
+            background: Color(50, 50, 50, 220),
+            border: Color(200, 200, 200, 255),
+            text: Color::WHITE,
+            icon: Color(200, 200, 200, 255),
+        };
+    }
+
     lazy_static! {
         pub static ref MARKER: Marker = Marker {
             radius: OUTER_RADIUS,
@@ -36,6 +65,14 @@ pub mod components {
         pub static ref POINT: Point = Point::Single(*MARKER);
         pub static ref CENTER_DOT: Point = *POINT;
     }
+
+    lazy_static! {
+        pub static ref GLOW: GlowOptions = GlowOptions {
+            radius: GLOW_RADIUS,
+            strength: GLOW_STRENGTH,
+            points: true,
+        };
+    }
     pub mod monocolor {
         use super::*;
         lazy_static! {
@@ -74,6 +111,34 @@ pub mod components {
                 colors: palettes::DEFAULT.to_vec(),
                 segments_per_color: SEGS_PER_COLOR,
                 bent: true,
+                space: ColorSpace::Srgb,
+                easing: Easing::Linear,
+            };
+        }
+    }
+    pub mod pigment {
+        use super::*;
+        lazy_static! {
+            pub static ref PIGMENT_LINE: Lines = Lines::Pigment {
+                colors: palettes::DEFAULT.to_vec(),
+                pigment: Pigment::Noise {
+                    scale: PIGMENT_SCALE,
+                    seed: 0
+                },
+                space: ColorSpace::Oklab,
+                bent: true,
+            };
+        }
+    }
+    pub mod zappy {
+        use super::*;
+        lazy_static! {
+            pub static ref ZAPPY_LINE: Lines = Lines::Zappy {
+                core: Color(255, 230, 250, 255),
+                halo: palettes::DEFAULT[0],
+                amplitude: ZAPPY_AMPLITUDE,
+                frequency: ZAPPY_FREQUENCY,
+                seed: 0,
             };
         }
     }
@@ -126,7 +191,9 @@ mod grids {
     use super::*;
     use gradient::*;
     use monocolor::*;
+    use pigment::*;
     use segment::*;
+    use zappy::*;
 
     lazy_static! {
         pub static ref INTRO_ANGLES: Vec<Vec<Angle>> =
@@ -193,6 +260,18 @@ mod grids {
             *GRADIENT_POINT
         );
     }
+    lazy_static! {
+        pub static ref PIGMENT: GridOptions = GridOptions::generate(
+            GridPatternOptions::Uniform(*GRADIENT_POINT_INTERSECTION, PIGMENT_LINE.clone()),
+            *GRADIENT_POINT
+        );
+    }
+    lazy_static! {
+        pub static ref ZAPPY: GridOptions = GridOptions::generate(
+            GridPatternOptions::Uniform(Intersections::Nothing, ZAPPY_LINE.clone()),
+            Point::None
+        );
+    }
     lazy_static! {
         pub static ref UNIFORM_SEGMENT: GridOptions = GridOptions::generate(
             GridPatternOptions::Uniform(*SEGMENT_INTERSECTION, SEGMENT_LINE.clone()),
@@ -210,3 +289,37 @@ mod grids {
     }
 }
 pub use grids::*;
+
+lazy_static! {
+    pub static ref ANIMATION: AnimationOptions = AnimationOptions {
+        format: AnimationFormat::Gif,
+        frame_rate: constants::FRAME_RATE,
+        pacing: Pacing::Segment(constants::SEGMENT_TIME),
+        pattern_delay: constants::PATTERN_DELAY,
+        hold_time: constants::HOLD_TIME,
+    };
+}
+
+lazy_static! {
+    pub static ref CAPTION: CaptionOptions = CaptionOptions {
+        position: CaptionPosition::Below,
+        size: constants::CAPTION_SIZE,
+        font: TextFont::Lato,
+        color: Color(150, 150, 150, 255),
+        max_width: constants::CAPTION_MAX_WIDTH,
+    };
+    pub static ref NUMBER_CAPTION: CaptionOptions = CaptionOptions {
+        position: CaptionPosition::Above,
+        size: constants::CAPTION_SIZE,
+        font: TextFont::SevenSegment,
+        color: Color(255, 107, 107, 255),
+        max_width: constants::CAPTION_MAX_WIDTH,
+    };
+    pub static ref MASK_CAPTION: CaptionOptions = CaptionOptions {
+        position: CaptionPosition::Above,
+        size: constants::CAPTION_SIZE,
+        font: TextFont::Lato,
+        color: Color(107, 178, 255, 255),
+        max_width: constants::CAPTION_MAX_WIDTH,
+    };
+}
@@ -1,3 +1,5 @@
+use crate::pattern_utils::HexCoord;
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 ///Color struct, using RGBA
@@ -5,6 +7,7 @@ pub struct Color(pub u8, pub u8, pub u8, pub u8);
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Options for drawing the lines of the patterns
 pub enum Lines {
 
@@ -17,6 +20,7 @@ pub enum Lines {
         bent: bool,
     },
     ///Gradient slowly switches between colors (gradient)
+    /// space: [ColorSpace::Srgb] and easing: [Easing::Linear] blend the same way gradients did before 0.3.0
     Gradient {
         ///Vec of colors to draw gradients between
         /// If the vec is empty, it's treated as Monocolor
@@ -28,6 +32,43 @@ pub enum Lines {
         segments_per_color: usize,
         ///Whether or not to have the segments bend around corners
         bent: bool,
+        ///Color space to blend between the colors in
+        /// (defaults to [ColorSpace::Srgb] when missing from serialized options)
+        #[cfg_attr(feature = "serde", serde(default))]
+        space: ColorSpace,
+        ///How the blend speeds up or slows down between each pair of colors
+        /// (defaults to [Easing::Linear] when missing from serialized options)
+        #[cfg_attr(feature = "serde", serde(default))]
+        easing: Easing,
+    },
+    ///Colors the lines by where they are rather than how far along the pattern they are
+    /// (the same way pigments color patterns ingame)
+    /// Every pattern on a grid samples the same field, so the whole spell looks like one continuous pigment
+    Pigment {
+        ///Colors the field blends between
+        /// If the vec is empty, it's treated as Monocolor
+        colors: Vec<Color>,
+        ///Shape of the field
+        pigment: Pigment,
+        ///Color space to blend between the colors in
+        space: ColorSpace,
+        ///Whether or not to have the segments bend around corners
+        bent: bool,
+    },
+    ///Jittery lines like lightning (the same way patterns look on the staff grid ingame)
+    /// drawn as a halo at the full line thickness with a thinner core on top
+    /// The jitter only depends on the seed, so the same seed always gives the same lines
+    Zappy {
+        ///Color of the thin line in the middle
+        core: Color,
+        ///Color of the wider line around the core
+        halo: Color,
+        ///Furthest the lines can be pushed to either side (in relation to the distance between points)
+        amplitude: f32,
+        ///Number of times the line zigzags between each pair of points (at most 255)
+        frequency: f32,
+        ///Seed for the jitter
+        seed: u32,
     },
     ///Changes colors whenever it reaches an intersection that's already had the current color
     SegmentColors {
@@ -40,8 +81,82 @@ pub enum Lines {
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Color space that gradients blend between colors in
+pub enum ColorSpace {
+    ///Blends the raw rgb values (can look muddy or dark in the middle)
+    #[default]
+    Srgb,
+    ///Blends the amount of light of each channel (brighter in the middle than Srgb)
+    LinearRgb,
+    ///Blends in a perceptual color space so the lightness changes evenly
+    Oklab,
+    ///Blends the hue (the shortest way around the color wheel), saturation and value
+    Hsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Field of colors used by [Lines::Pigment]
+/// Positions are in grid units from the top left of the grid
+/// (the distance between points for a [HexGrid](crate::grids::HexGrid), the tile size for a [SquareGrid](crate::grids::SquareGrid))
+/// When a pattern is drawn on its own, they're in lattice units from its start instead
+pub enum Pigment {
+    ///Stripes that go through all the colors and repeat
+    Linear {
+        ///Direction the colors change in (in degrees clockwise from pointing right)
+        angle: f32,
+        ///Distance to go through all of the colors once
+        period: f32,
+    },
+    ///Rings around a center that go through all the colors and repeat
+    Radial {
+        ///Center of the rings
+        center: HexCoord,
+        ///Distance to go through all of the colors once
+        period: f32,
+    },
+    ///Smooth random blotches of color (from the first color to the last)
+    Noise {
+        ///Rough size of each blotch
+        scale: f32,
+        ///Seed for the random field (the same seed always gives the same field)
+        seed: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Curve for how fast a gradient blends from one color to the next
+pub enum Easing {
+    ///Blends at a constant speed
+    #[default]
+    Linear,
+    ///Starts slow and speeds up
+    EaseIn,
+    ///Starts fast and slows down
+    EaseOut,
+    ///Starts and ends slow (staying on each color a bit longer)
+    EaseInOut,
+}
+
+impl Easing {
+    ///Eased amount for the given progress (both from 0 to 1)
+    pub fn apply(self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => progress,
+            Easing::EaseIn => progress * progress,
+            Easing::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+            Easing::EaseInOut => progress * progress * (3.0 - 2.0 * progress),
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Options for drawing the triangle/arrow between color changes on the Segment Renderer
 pub enum Triangle {
     ///None, simply don't draw them
@@ -69,6 +184,7 @@ pub enum Triangle {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Options for drawing overlapping segments (impossible patterns)
 pub enum CollisionOption {
     ///Draws the first segment and then dashes of the given color for the rest
@@ -88,6 +204,7 @@ pub enum CollisionOption {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Options for what to do when you get too many parallel lines
 pub enum OverloadOptions {
     ///same as [CollisionOption::Dashes] (just draws dashes of the given color over the first line)
@@ -100,6 +217,7 @@ pub enum OverloadOptions {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Options for drawing points at the grid points/intersections
 pub enum Point {
     ///Doesn't draw any points
@@ -111,6 +229,7 @@ pub enum Point {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Specifier for how to draw a shape (not necessarily a circle)
 pub struct Marker {
     ///The color to draw it with
@@ -121,6 +240,7 @@ pub struct Marker {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Specifier for how to draw the start and end points on a pattern
 pub enum EndPoint {
     ///Draw a normal point
@@ -133,6 +253,7 @@ pub enum EndPoint {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///How to draw all the points in a pattern, including start, end, and middle points
 pub enum Intersections {
     ///Doesn't draw any points
@@ -337,11 +458,9 @@ impl Lines {
     pub fn get_max_radius(&self) -> f32 {
         match self {
             Lines::Monocolor { color: _, bent: _ }
-            | Lines::Gradient {
-                colors: _,
-                segments_per_color: _,
-                bent: _,
-            } => 0.0,
+            | Lines::Gradient { .. }
+            | Lines::Pigment { .. } => 0.0,
+            Lines::Zappy { amplitude, .. } => amplitude.abs(),
             Lines::SegmentColors {
                 colors: _,
                 triangles: arrows,
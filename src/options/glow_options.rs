@@ -0,0 +1,13 @@
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Options for a blurred glow drawn under the patterns (tinted with the colors of the lines)
+/// similar to how patterns look when they're being drawn ingame
+/// Added to a grid with [HexGrid::with_glow](crate::grids::HexGrid::with_glow) or [SquareGrid::with_glow](crate::grids::SquareGrid::with_glow)
+pub struct GlowOptions {
+    ///How far the glow spreads out from the lines (in relation to the distance between points)
+    pub radius: f32,
+    ///How bright the glow is (1 for the glow right next to a line to be about half as bright as the line)
+    pub strength: f32,
+    ///Whether the points (intersections and center dots) glow as well
+    pub points: bool,
+}
@@ -2,8 +2,23 @@
 mod draw_options;
 pub use draw_options::*;
 
+mod color;
+pub use color::ColorParseError;
+
 mod pattern_grid_options;
 pub use pattern_grid_options::*;
 
+mod animation_options;
+pub use animation_options::*;
+
+mod tile_options;
+pub use tile_options::*;
+
+mod caption_options;
+pub use caption_options::*;
+
+mod glow_options;
+pub use glow_options::*;
+
 pub mod defaults;
 pub mod palettes;
@@ -4,6 +4,8 @@ use lazy_static::lazy_static;
 
 use crate::options::Color;
 
+pub mod pigments;
+
 lazy_static! {
     pub static ref ALL: Vec<Vec<Color>> = vec![
         DEFAULT.to_vec(),
@@ -0,0 +1,298 @@
+//! Palettes matching the pigments from Hex Casting.
+//! Each one can be used anywhere the other palettes can
+//! (eg. `GridPatternOptions::gen_changing_gradient(intersections, vec![pigments::TRANSGENDER.to_vec()], true)`)
+//! or looked up from its name with [by_name].
+use lazy_static::lazy_static;
+
+use crate::options::Color;
+
+lazy_static! {
+    ///Every pigment along with the name [by_name] finds it with
+    pub static ref NAMED: Vec<(&'static str, Vec<Color>)> = vec![
+        ("white", WHITE.to_vec()),
+        ("orange", ORANGE.to_vec()),
+        ("magenta", MAGENTA.to_vec()),
+        ("light_blue", LIGHT_BLUE.to_vec()),
+        ("yellow", YELLOW.to_vec()),
+        ("lime", LIME.to_vec()),
+        ("pink", PINK.to_vec()),
+        ("gray", GRAY.to_vec()),
+        ("light_gray", LIGHT_GRAY.to_vec()),
+        ("cyan", CYAN.to_vec()),
+        ("purple", PURPLE.to_vec()),
+        ("blue", BLUE.to_vec()),
+        ("brown", BROWN.to_vec()),
+        ("green", GREEN.to_vec()),
+        ("red", RED.to_vec()),
+        ("black", BLACK.to_vec()),
+        ("agender", AGENDER.to_vec()),
+        ("aroace", AROACE.to_vec()),
+        ("aromantic", AROMANTIC.to_vec()),
+        ("asexual", ASEXUAL.to_vec()),
+        ("bisexual", BISEXUAL.to_vec()),
+        ("demiboy", DEMIBOY.to_vec()),
+        ("demigirl", DEMIGIRL.to_vec()),
+        ("gay", GAY.to_vec()),
+        ("genderfluid", GENDERFLUID.to_vec()),
+        ("genderqueer", GENDERQUEER.to_vec()),
+        ("intersex", INTERSEX.to_vec()),
+        ("lesbian", LESBIAN.to_vec()),
+        ("nonbinary", NONBINARY.to_vec()),
+        ("pansexual", PANSEXUAL.to_vec()),
+        ("plural", PLURAL.to_vec()),
+        ("transgender", TRANSGENDER.to_vec()),
+        ("amethyst", AMETHYST.to_vec()),
+    ];
+    ///Every pigment (in the same order as [struct@NAMED])
+    pub static ref ALL: Vec<Vec<Color>> = NAMED.iter().map(|(_, colors)| colors.clone()).collect();
+
+    //dye pigments (a single color each, so gradients using them are drawn solid)
+    pub static ref WHITE: Vec<Color> = vec![Color(249, 255, 254, 255)];
+    pub static ref ORANGE: Vec<Color> = vec![Color(249, 128, 29, 255)];
+    pub static ref MAGENTA: Vec<Color> = vec![Color(199, 78, 189, 255)];
+    pub static ref LIGHT_BLUE: Vec<Color> = vec![Color(58, 179, 218, 255)];
+    pub static ref YELLOW: Vec<Color> = vec![Color(254, 216, 61, 255)];
+    pub static ref LIME: Vec<Color> = vec![Color(128, 199, 31, 255)];
+    pub static ref PINK: Vec<Color> = vec![Color(243, 139, 170, 255)];
+    pub static ref GRAY: Vec<Color> = vec![Color(71, 79, 82, 255)];
+    pub static ref LIGHT_GRAY: Vec<Color> = vec![Color(157, 157, 151, 255)];
+    pub static ref CYAN: Vec<Color> = vec![Color(22, 156, 156, 255)];
+    pub static ref PURPLE: Vec<Color> = vec![Color(137, 50, 184, 255)];
+    pub static ref BLUE: Vec<Color> = vec![Color(60, 68, 170, 255)];
+    pub static ref BROWN: Vec<Color> = vec![Color(131, 84, 50, 255)];
+    pub static ref GREEN: Vec<Color> = vec![Color(94, 124, 22, 255)];
+    pub static ref RED: Vec<Color> = vec![Color(176, 46, 38, 255)];
+    pub static ref BLACK: Vec<Color> = vec![Color(29, 29, 33, 255)];
+
+    //pride pigments (the stripes of each flag from top to bottom)
+    pub static ref AGENDER: Vec<Color> = vec![
+        Color(0, 0, 0, 255),
+        Color(188, 196, 199, 255),
+        Color(255, 255, 255, 255),
+        Color(183, 246, 132, 255),
+        Color(255, 255, 255, 255),
+        Color(188, 196, 199, 255),
+        Color(0, 0, 0, 255),
+    ];
+    pub static ref AROACE: Vec<Color> = vec![
+        Color(226, 140, 0, 255),
+        Color(236, 205, 0, 255),
+        Color(255, 255, 255, 255),
+        Color(98, 174, 220, 255),
+        Color(32, 56, 86, 255),
+    ];
+    pub static ref AROMANTIC: Vec<Color> = vec![
+        Color(61, 165, 66, 255),
+        Color(167, 211, 121, 255),
+        Color(255, 255, 255, 255),
+        Color(169, 169, 169, 255),
+        Color(0, 0, 0, 255),
+    ];
+    pub static ref ASEXUAL: Vec<Color> = vec![
+        Color(0, 0, 0, 255),
+        Color(163, 163, 163, 255),
+        Color(255, 255, 255, 255),
+        Color(128, 0, 128, 255),
+    ];
+    pub static ref BISEXUAL: Vec<Color> = vec![
+        Color(214, 2, 112, 255),
+        Color(155, 79, 150, 255),
+        Color(0, 56, 168, 255),
+    ];
+    pub static ref DEMIBOY: Vec<Color> = vec![
+        Color(127, 127, 127, 255),
+        Color(196, 196, 196, 255),
+        Color(157, 215, 234, 255),
+        Color(255, 255, 255, 255),
+        Color(157, 215, 234, 255),
+        Color(196, 196, 196, 255),
+        Color(127, 127, 127, 255),
+    ];
+    pub static ref DEMIGIRL: Vec<Color> = vec![
+        Color(127, 127, 127, 255),
+        Color(196, 196, 196, 255),
+        Color(253, 173, 200, 255),
+        Color(255, 255, 255, 255),
+        Color(253, 173, 200, 255),
+        Color(196, 196, 196, 255),
+        Color(127, 127, 127, 255),
+    ];
+    pub static ref GAY: Vec<Color> = vec![
+        Color(7, 141, 112, 255),
+        Color(38, 206, 170, 255),
+        Color(152, 232, 193, 255),
+        Color(255, 255, 255, 255),
+        Color(123, 173, 226, 255),
+        Color(80, 73, 204, 255),
+        Color(61, 26, 120, 255),
+    ];
+    pub static ref GENDERFLUID: Vec<Color> = vec![
+        Color(255, 118, 164, 255),
+        Color(255, 255, 255, 255),
+        Color(192, 17, 215, 255),
+        Color(0, 0, 0, 255),
+        Color(47, 60, 190, 255),
+    ];
+    pub static ref GENDERQUEER: Vec<Color> = vec![
+        Color(181, 126, 220, 255),
+        Color(255, 255, 255, 255),
+        Color(74, 129, 35, 255),
+    ];
+    pub static ref INTERSEX: Vec<Color> = vec![
+        Color(255, 216, 0, 255),
+        Color(121, 2, 170, 255),
+        Color(255, 216, 0, 255),
+    ];
+    pub static ref LESBIAN: Vec<Color> = vec![
+        Color(213, 45, 0, 255),
+        Color(239, 118, 39, 255),
+        Color(255, 154, 86, 255),
+        Color(255, 255, 255, 255),
+        Color(209, 98, 164, 255),
+        Color(181, 86, 144, 255),
+        Color(163, 2, 98, 255),
+    ];
+    pub static ref NONBINARY: Vec<Color> = vec![
+        Color(252, 244, 52, 255),
+        Color(255, 255, 255, 255),
+        Color(156, 89, 209, 255),
+        Color(44, 44, 44, 255),
+    ];
+    pub static ref PANSEXUAL: Vec<Color> = vec![
+        Color(255, 33, 140, 255),
+        Color(255, 216, 0, 255),
+        Color(33, 177, 255, 255),
+    ];
+    pub static ref PLURAL: Vec<Color> = vec![
+        Color(48, 198, 159, 255),
+        Color(52, 125, 201, 255),
+        Color(102, 74, 195, 255),
+        Color(178, 61, 199, 255),
+        Color(216, 74, 192, 255),
+    ];
+    pub static ref TRANSGENDER: Vec<Color> = vec![
+        Color(91, 206, 250, 255),
+        Color(245, 169, 184, 255),
+        Color(255, 255, 255, 255),
+        Color(245, 169, 184, 255),
+        Color(91, 206, 250, 255),
+    ];
+
+    //special pigments
+    pub static ref AMETHYST: Vec<Color> = vec![
+        Color(84, 57, 138, 255),
+        Color(141, 106, 204, 255),
+        Color(207, 160, 243, 255),
+        Color(115, 79, 181, 255),
+    ];
+}
+
+///Other names players use for the pigments
+const ALIASES: [(&str, &str); 9] = [
+    ("grey", "gray"),
+    ("lightgrey", "light_gray"),
+    ("ace", "asexual"),
+    ("aro", "aromantic"),
+    ("bi", "bisexual"),
+    ("enby", "nonbinary"),
+    ("pan", "pansexual"),
+    ("trans", "transgender"),
+    ("mlm", "gay"),
+];
+
+///Finds a pigment from its name (eg. "Transgender Pigment", "light_blue", "trans")
+/// Case, spaces, dashes, underscores and the word "pigment" are ignored
+/// Soulglimmer isn't included since it depends on the player (see [soulglimmer])
+pub fn by_name(name: &str) -> Option<Vec<Color>> {
+    let name = name
+        .chars()
+        .filter(|char| char.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    let name = name.strip_prefix("pigment").unwrap_or(&name);
+    let name = name.strip_suffix("pigment").unwrap_or(name);
+
+    let name = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, pigment)| *pigment);
+
+    NAMED
+        .iter()
+        .find(|(pigment, _)| pigment.replace('_', "") == name.replace('_', ""))
+        .map(|(_, colors)| colors.clone())
+}
+
+///Stand-in for the soulglimmer pigment of the given player (the UUID as a single number)
+/// Every UUID gets its own pair of colors (a light one followed by a dark one) picked from a hash of it,
+/// so it's stable for each player but won't match the colors they get ingame
+pub fn soulglimmer(uuid: u128) -> Vec<Color> {
+    let mut state = (uuid as u64) ^ ((uuid >> 64) as u64);
+    let mut random = || {
+        //splitmix64
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^= value >> 31;
+        (value >> 40) as f32 / (1u64 << 24) as f32
+    };
+
+    let light = Color::from_hsv(random() * 360.0, 0.4 + random() * 0.4, 0.7 + random() * 0.3);
+    let dark = Color::from_hsv(random() * 360.0, 0.7 + random() * 0.3, 0.2 + random() * 0.5);
+
+    vec![light, dark]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn by_name_exact() {
+        for (name, colors) in NAMED.iter() {
+            assert_eq!(by_name(name).as_ref(), Some(colors), "{name}");
+        }
+    }
+
+    #[test]
+    fn by_name_case_and_spacing() {
+        for name in ["light_blue", "Light Blue", "LIGHT-BLUE", "lightblue", "  light  blue "] {
+            assert_eq!(by_name(name), Some(LIGHT_BLUE.to_vec()), "{name}");
+        }
+    }
+
+    #[test]
+    fn by_name_pigment_word() {
+        for name in ["Transgender Pigment", "transgender_pigment", "Pigment: Transgender", "PIGMENT transgender"] {
+            assert_eq!(by_name(name), Some(TRANSGENDER.to_vec()), "{name}");
+        }
+        assert_eq!(by_name("Light Gray Pigment"), Some(LIGHT_GRAY.to_vec()));
+    }
+
+    #[test]
+    fn by_name_aliases() {
+        assert_eq!(by_name("trans"), Some(TRANSGENDER.to_vec()));
+        assert_eq!(by_name("Enby Pigment"), Some(NONBINARY.to_vec()));
+        assert_eq!(by_name("grey"), Some(GRAY.to_vec()));
+        assert_eq!(by_name("Light Grey"), Some(LIGHT_GRAY.to_vec()));
+        assert_eq!(by_name("ACE"), Some(ASEXUAL.to_vec()));
+    }
+
+    #[test]
+    fn by_name_unknown() {
+        for name in ["", "pigment", "soulglimmer", "rainbow", "trans pigment pigment"] {
+            assert_eq!(by_name(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn soulglimmer_per_player() {
+        let player = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321;
+
+        assert_eq!(soulglimmer(player), soulglimmer(player));
+        assert_ne!(soulglimmer(player), soulglimmer(player + 1));
+        assert_eq!(soulglimmer(player).len(), 2);
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
     defaults,
-    options::{Intersections, Lines, Triangle, Color},
+    options::{Color, ColorSpace, Easing, Intersections, Lines, Triangle},
     pattern_utils::Angle,
 };
 
 use super::{defaults::constants, CollisionOption, Point};
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Main struct for all pattern rendering options
 pub struct GridOptions {
     ///Thickness of line in relation to distance between points
@@ -21,6 +22,7 @@ pub struct GridOptions {
 
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Struct that holds the different variations of GridPatterns
 pub enum GridPatternOptions {
     ///Uniform means that all patterns will be rendered in the same way
@@ -32,8 +34,10 @@ pub enum GridPatternOptions {
         ///Variations to use, starts at the first and goes up when it reaches an intro, goes down when reaching a retro
         variations: Vec<(Intersections, Lines)>,
         ///Vec of the angle_sigs of intro patterns
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::angle_sigs"))]
         intros: Vec<Vec<Angle>>,
         ///Vec of angle_sigs of retro patterns
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::angle_sigs"))]
         retros: Vec<Vec<Angle>>,
     },
 }
@@ -106,6 +110,8 @@ impl GridPatternOptions {
                     colors,
                     segments_per_color: constants::SEGS_PER_COLOR,
                     bent,
+                    space: ColorSpace::default(),
+                    easing: Easing::default(),
                 })
                 .collect(),
         )
@@ -0,0 +1,16 @@
+use super::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Options for drawing the non-pattern iotas (strings, numbers, vectors, etc.) on a grid
+/// Each one is drawn as a box with a label (and an icon for some types) in place of a pattern
+pub struct TileOptions {
+    ///Color to fill the box with
+    pub background: Color,
+    ///Color of the outline around the box
+    pub border: Color,
+    ///Color of the label
+    pub text: Color,
+    ///Color of the icons for null, garbage, entity and vector iotas
+    pub icon: Color,
+}
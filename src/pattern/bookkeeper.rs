@@ -0,0 +1,166 @@
+use crate::pattern_utils::{Angle, Direction};
+
+use super::Pattern;
+
+///Character for an iota that Bookkeeper's Gambit keeps
+const KEEP: char = '-';
+///Character for an iota that Bookkeeper's Gambit removes
+const REMOVE: char = 'v';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaskParseError {
+    ///The mask doesn't have any characters
+    Empty,
+    ///The mask has a character other than - (keep) or v (remove)
+    InvalidChar { mask: String, char: char },
+}
+
+impl Pattern {
+    ///Creates the Bookkeeper's Gambit for the mask
+    /// The mask has a - for each iota to keep and a v for each iota to remove (eg. `v-v--`)
+    /// Each - is a flat line and each v is a dip going down and back up
+    pub fn from_bookkeeper_mask(mask: &str) -> Result<Pattern, MaskParseError> {
+        let flat = Direction::East;
+
+        let mut directions = Vec::new();
+        for char in mask.chars() {
+            match char {
+                KEEP => directions.push(flat),
+                REMOVE => {
+                    directions.push(flat + Angle::Right);
+                    directions.push(flat + Angle::Left);
+                }
+                char => {
+                    return Err(MaskParseError::InvalidChar {
+                        mask: mask.to_string(),
+                        char,
+                    })
+                }
+            }
+        }
+
+        let start_direction = *directions.first().ok_or(MaskParseError::Empty)?;
+        let angles = directions
+            .windows(2)
+            .map(|directions| directions[1] - directions[0])
+            .collect();
+
+        Ok(Pattern::new(start_direction, angles))
+    }
+
+    ///Mask of the pattern if it's a Bookkeeper's Gambit (see [Pattern::from_bookkeeper_mask])
+    /// The pattern can be drawn in any direction, the same way as ingame
+    pub fn bookkeeper_mask(&self) -> Option<String> {
+        let mut directions = vec![self.start_direction];
+        for angle in &self.angles {
+            directions.push(*directions.last().unwrap() + *angle);
+        }
+
+        //patterns that start with a dip start by going down from the flat direction
+        let flat = if self.angles.first() == Some(&Angle::BackLeft) {
+            self.start_direction + Angle::Left
+        } else {
+            self.start_direction
+        };
+
+        let mut mask = String::new();
+        let mut directions = directions.into_iter();
+
+        while let Some(direction) = directions.next() {
+            match direction - flat {
+                Angle::Forward => mask.push(KEEP),
+                Angle::Right => {
+                    let next = directions.next().map(|next| next - flat);
+                    if next != Some(Angle::Left) {
+                        return None;
+                    }
+                    mask.push(REMOVE);
+                }
+                _ => return None,
+            }
+        }
+
+        Some(mask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pattern::PatternIssue;
+
+    use super::*;
+
+    ///Masks along with their ingame pattern
+    const KNOWN: [(&str, &str); 7] = [
+        ("-", "EAST"),
+        ("v", "SOUTH_EAST a"),
+        ("--", "EAST w"),
+        ("-v", "EAST ea"),
+        ("v-", "SOUTH_EAST ae"),
+        ("vv", "SOUTH_EAST ada"),
+        ("v-v--", "SOUTH_EAST aeeaew"),
+    ];
+
+    fn pattern(pattern: &str) -> Pattern {
+        Pattern::try_from(pattern).unwrap()
+    }
+
+    #[test]
+    fn from_bookkeeper_mask_known_masks() {
+        for (mask, expected) in KNOWN {
+            assert_eq!(Pattern::from_bookkeeper_mask(mask), Ok(pattern(expected)), "{mask}");
+        }
+    }
+
+    #[test]
+    fn bookkeeper_mask_known_patterns() {
+        for (mask, expected) in KNOWN {
+            assert_eq!(pattern(expected).bookkeeper_mask().as_deref(), Some(mask), "{expected}");
+        }
+    }
+
+    #[test]
+    fn bookkeeper_mask_any_direction() {
+        assert_eq!(pattern("WEST ea").bookkeeper_mask().as_deref(), Some("-v"));
+        assert_eq!(pattern("NORTH_EAST aeeaew").bookkeeper_mask().as_deref(), Some("v-v--"));
+    }
+
+    #[test]
+    fn bookkeeper_mask_round_trips() {
+        //every mask up to 6 long
+        let mut masks = vec![String::new()];
+        for _ in 0..6 {
+            masks = masks
+                .iter()
+                .flat_map(|mask| [format!("{mask}{KEEP}"), format!("{mask}{REMOVE}")])
+                .collect();
+
+            for mask in &masks {
+                let pattern = Pattern::from_bookkeeper_mask(mask).unwrap();
+                //"-" is a single line, which is the only issue allowed
+                let issues = pattern.validate();
+                assert!(issues.iter().all(|issue| *issue == PatternIssue::EmptySignature), "{mask}");
+                assert_eq!(pattern.bookkeeper_mask().as_ref(), Some(mask));
+            }
+        }
+    }
+
+    #[test]
+    fn bookkeeper_mask_other_patterns() {
+        for other in ["EAST qaq", "EAST aqaaw", "SOUTH_EAST aa", "EAST ee", "SOUTH_EAST ad"] {
+            assert_eq!(pattern(other).bookkeeper_mask(), None, "{other}");
+        }
+    }
+
+    #[test]
+    fn from_bookkeeper_mask_errors() {
+        assert_eq!(Pattern::from_bookkeeper_mask(""), Err(MaskParseError::Empty));
+        assert_eq!(
+            Pattern::from_bookkeeper_mask("v-x"),
+            Err(MaskParseError::InvalidChar {
+                mask: "v-x".to_string(),
+                char: 'x'
+            })
+        );
+    }
+}
@@ -1,49 +1,33 @@
 use std::collections::HashMap;
 
-use tiny_skia::{
-    GradientStop, LinearGradient, Paint, Pixmap, SpreadMode, Stroke, Transform,
-};
-
 use crate::pattern_utils::{Coord, HexCoord, LineDrawer};
+use crate::render::{LinePaint, LineStyle, RenderTarget};
 
-use crate::options::Color;
+use crate::options::{Color, ColorSpace, Easing};
 
-use super::Pattern;
+use super::{progress::split_progress, Pattern};
 
 #[allow(clippy::too_many_arguments)]
 pub fn draw_gradient_lines(
     pattern: &Pattern,
-    pixmap: &mut Pixmap,
-    stroke: &Stroke,
+    target: &mut dyn RenderTarget,
+    stroke: &LineStyle,
     origin: HexCoord,
     scale: f32,
     colors: &[Color],
     segs_per_color: usize,
     bent_corners: bool,
+    space: ColorSpace,
+    easing: Easing,
+    progress: f32,
 ) -> Color {
     let segments = pattern.path.len() as f32 - 1.0;
 
-    let mut grad_colors = Vec::new();
-
-    for col in colors.iter().take(pattern.path.len() / segs_per_color + 2) {
-        //let col = colors[i];
-        let col = tiny_skia::Color::from(*col);
-        grad_colors.push([col.red(), col.green(), col.blue(), col.alpha()]);
-    }
-
-    let mut grad_diffs = Vec::new();
-
-    for i in 1..grad_colors.len() {
-        let mut col = [0.0; 4];
-        for (j, col) in col.iter_mut().enumerate() {
-            *col = grad_colors[i][j] - grad_colors[i - 1][j];
-        }
-        grad_diffs.push(col);
-    }
-
-    let mut cur_color = grad_colors[0];
+    let grad_colors = &colors[..colors.len().min(pattern.path.len() / segs_per_color + 2)];
 
-    let grad_segments = grad_colors.len() - 1;
+    //a single color is blended with itself (so it's drawn as a solid line)
+    let grad_segments = (grad_colors.len() - 1).max(1);
+    let last_color = grad_colors.len() - 1;
 
     let mut loc_prev = origin + HexCoord::from(pattern.path[0]) * scale;
 
@@ -58,44 +42,45 @@ pub fn draw_gradient_lines(
             }
         }
     }
-    let paint = Paint::default();
-    let mut line_drawer = LineDrawer::new(origin, stroke.clone(), paint);
+    let mut line_drawer = LineDrawer::new(origin, stroke.clone(), LinePaint::Solid(colors[0]));
 
-    let mut prev_shade_color =
-        tiny_skia::Color::from_rgba(cur_color[0], cur_color[1], cur_color[2], cur_color[3]).unwrap();
+    let mut prev_shade_color = grad_colors[0];
+
+    let (full, partial) = split_progress(pattern.path.len() - 1, progress);
+    let drawn = if partial > 0.0 { full + 1 } else { full };
+
+    for i in 1..=drawn {
+        let is_partial = i > full;
 
-    for i in 1..pattern.path.len() {
         let mut loc_next = origin + HexCoord::from(pattern.path[i]) * scale;
 
-        let progress = (i - 1) as f32 / segments;
+        let progress = if is_partial {
+            let loc_start = origin + HexCoord::from(pattern.path[i - 1]) * scale;
+            loc_next = loc_start + (loc_next - loc_start) * partial;
+            ((i as f32 - 2.0 + partial) / segments).max(0.0)
+        } else {
+            (i - 1) as f32 / segments
+        };
         let grad_seg = (progress * grad_segments as f32) as usize;
 
         let seg_progress =
             (progress - (grad_seg as f32 / grad_segments as f32)) * grad_segments as f32;
 
-        for (j, cur_color) in cur_color.iter_mut().enumerate() {
-            *cur_color =
-                (grad_colors[grad_seg][j] + grad_diffs[grad_seg][j] * seg_progress).clamp(0.0, 1.0);
-        }
-
-        let cur_col =
-            tiny_skia::Color::from_rgba(cur_color[0], cur_color[1], cur_color[2], cur_color[3]).unwrap();
-
-        line_drawer.set_shader(
-            LinearGradient::new(
-                tiny_skia::Point::from_xy(loc_prev.0, loc_prev.1),
-                tiny_skia::Point::from_xy(loc_next.0, loc_next.1),
-                vec![
-                    GradientStop::new(0.0, prev_shade_color),
-                    GradientStop::new(1.0, cur_col),
-                ],
-                SpreadMode::Pad,
-                Transform::identity(),
-            )
-            .unwrap(),
+        let cur_col = space.mix(
+            grad_colors[grad_seg.min(last_color)],
+            grad_colors[(grad_seg + 1).min(last_color)],
+            easing.apply(seg_progress),
         );
 
+        line_drawer.set_paint(LinePaint::Gradient {
+            start: loc_prev,
+            end: loc_next,
+            start_color: prev_shade_color,
+            end_color: cur_col,
+        });
+
         if bent_corners
+            && !is_partial
             && visit_count.get(&pattern.path[i]).unwrap() > &1
             && pattern.path.len() - 1 != i
         {
@@ -104,6 +89,12 @@ pub fn draw_gradient_lines(
             let stop_point = loc_next - (loc_next - loc_prev) * bend_amount;
             line_drawer.line_to(stop_point);
 
+            //don't bend further than the next segment has been drawn
+            let bend_amount = if i == full {
+                bend_amount.min(partial)
+            } else {
+                bend_amount
+            };
             loc_next = loc_next
                 + (origin + HexCoord::from(pattern.path[i + 1]) * scale - loc_next) * bend_amount;
         }
@@ -113,7 +104,11 @@ pub fn draw_gradient_lines(
         prev_shade_color = cur_col;
     }
 
-    line_drawer.draw_all(pixmap);
+    line_drawer.draw_all(target);
 
-    colors[grad_colors.len() - 1]
+    if drawn == pattern.path.len() - 1 {
+        grad_colors[last_color]
+    } else {
+        prev_shade_color
+    }
 }
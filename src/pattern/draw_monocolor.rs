@@ -1,25 +1,42 @@
 use std::collections::HashMap;
 
-use tiny_skia::{Paint, Pixmap, Stroke};
-
 use crate::pattern_utils::{Coord, HexCoord, LineDrawer};
+use crate::render::{LinePaint, LineStyle, RenderTarget};
 
 use crate::options::Color;
 
-use super::Pattern;
+use super::{progress::split_progress, Pattern};
 
+#[allow(clippy::too_many_arguments)]
 pub fn draw_monocolor_lines(
     pattern: &Pattern,
-    pixmap: &mut Pixmap,
-    stroke: &Stroke,
+    target: &mut dyn RenderTarget,
+    stroke: &LineStyle,
     origin: HexCoord,
     scale: f32,
     color: Color,
     bent_corners: bool,
+    progress: f32,
 ) {
-    let mut paint = Paint::default();
-    paint.set_color(color.into());
+    let points = get_line_points(pattern, origin, scale, bent_corners, progress);
+
+    let mut line_drawer = LineDrawer::new(points[0], stroke.clone(), LinePaint::Solid(color));
+    for point in &points[1..] {
+        line_drawer.line_to(*point);
+    }
 
+    line_drawer.draw_all(target);
+}
+
+///Points (in pixels) that the line of the pattern goes through, with the corners cut off if bent
+/// The line stops partway through a segment depending on the progress
+pub fn get_line_points(
+    pattern: &Pattern,
+    origin: HexCoord,
+    scale: f32,
+    bent_corners: bool,
+    progress: f32,
+) -> Vec<HexCoord> {
     let mut visit_count: HashMap<Coord, usize> = HashMap::new();
 
     if bent_corners {
@@ -32,9 +49,12 @@ pub fn draw_monocolor_lines(
         }
     }
 
-    let mut line_drawer = LineDrawer::new(origin, stroke.clone(), paint);
+    let (full, partial) = split_progress(pattern.path.len() - 1, progress);
+    let bend_amount = 0.2;
 
-    for (i, line) in pattern.path.iter().enumerate() {
+    let mut points = vec![origin];
+
+    for (i, line) in pattern.path.iter().enumerate().take(full + 1) {
         let current = HexCoord::from(*line) * scale + origin;
 
         if bent_corners
@@ -44,21 +64,39 @@ pub fn draw_monocolor_lines(
             let next = current;
 
             let current = HexCoord::from(pattern.path[i - 1]) * scale + origin;
-            let bend_amount = 0.2;
 
             let stop_point = next - (next - current) * bend_amount;
-            line_drawer.line_to(stop_point);
+            points.push(stop_point);
 
             if pattern.path.len() - 1 != i {
-                line_drawer.line_to(
+                //don't bend further than the next segment has been drawn
+                let bend_amount = if i == full {
+                    bend_amount.min(partial)
+                } else {
+                    bend_amount
+                };
+                points.push(
                     next + (origin + HexCoord::from(pattern.path[i + 1]) * scale - next)
                         * bend_amount,
                 );
             }
         } else {
-            line_drawer.line_to(current);
+            points.push(current);
         }
     }
 
-    line_drawer.draw_all(pixmap);
+    if partial > 0.0 {
+        let prev = HexCoord::from(pattern.path[full]) * scale + origin;
+        let next = HexCoord::from(pattern.path[full + 1]) * scale + origin;
+
+        //the last segment stops short when bent, so the partial one should too
+        let amount = if bent_corners && full + 1 == pattern.path.len() - 1 {
+            partial * (1.0 - bend_amount)
+        } else {
+            partial
+        };
+        points.push(prev + (next - prev) * amount);
+    }
+
+    points
 }
@@ -0,0 +1,294 @@
+use crate::options::{Color, ColorSpace, Pigment};
+use crate::pattern_utils::{HexCoord, LineDrawer};
+use crate::render::{LinePaint, LineStyle, RenderTarget};
+
+use super::{draw_monocolor::get_line_points, Pattern};
+
+///Longest piece (in relation to the distance between points) that each line is split into
+/// so the colors follow the field instead of blending straight across a whole segment
+const PIECE_LENGTH: f32 = 0.25;
+
+///Draws the lines colored by a [Pigment] field
+/// returns the colors at the start and end of the line
+/// * frame - Location (in pixels) of the origin of the field and the size (in pixels) of one unit of it
+#[allow(clippy::too_many_arguments)]
+pub fn draw_pigment_lines(
+    pattern: &Pattern,
+    target: &mut dyn RenderTarget,
+    stroke: &LineStyle,
+    origin: HexCoord,
+    scale: f32,
+    colors: &[Color],
+    pigment: &Pigment,
+    space: ColorSpace,
+    bent_corners: bool,
+    frame: (HexCoord, f32),
+    progress: f32,
+) -> (Color, Color) {
+    let color_at = |point: HexCoord| pigment.color_at(colors, space, (point - frame.0) / frame.1);
+
+    let points = get_line_points(pattern, origin, scale, bent_corners, progress);
+    let start_color = color_at(points[0]);
+
+    let mut line_drawer = LineDrawer::new(points[0], stroke.clone(), LinePaint::Solid(start_color));
+
+    let mut prev_color = start_color;
+    for line in points.windows(2) {
+        let pieces = ((line[1] - line[0]).magnitude() / (PIECE_LENGTH * scale)).ceil().max(1.0) as usize;
+
+        for piece in 1..=pieces {
+            let start = line[0] + (line[1] - line[0]) * ((piece - 1) as f32 / pieces as f32);
+            let end = line[0] + (line[1] - line[0]) * (piece as f32 / pieces as f32);
+            let end_color = color_at(end);
+
+            line_drawer.set_paint(LinePaint::Gradient {
+                start,
+                end,
+                start_color: prev_color,
+                end_color,
+            });
+            line_drawer.line_to(end);
+
+            prev_color = end_color;
+        }
+    }
+
+    line_drawer.draw_all(target);
+
+    (start_color, prev_color)
+}
+
+impl Pigment {
+    ///Color of the field at the given position (see [Pigment] for the units)
+    /// * colors - Colors to blend between (white if it's empty)
+    /// * space - Color space to blend between the colors in
+    pub fn color_at(&self, colors: &[Color], space: ColorSpace, position: HexCoord) -> Color {
+        match self {
+            Pigment::Linear { angle, period } => {
+                let direction = HexCoord(angle.to_radians().cos(), angle.to_radians().sin());
+                let distance = position.0 * direction.0 + position.1 * direction.1;
+                cycle_colors(colors, space, distance / period)
+            }
+            Pigment::Radial { center, period } => {
+                cycle_colors(colors, space, (position - *center).magnitude() / period)
+            }
+            Pigment::Noise { scale, seed } => {
+                spread_colors(colors, space, value_noise(position / *scale, *seed))
+            }
+        }
+    }
+}
+
+///Color at the given amount through the colors, going back to the first color after the last one
+/// (so every whole number is the first color)
+fn cycle_colors(colors: &[Color], space: ColorSpace, amount: f32) -> Color {
+    if colors.is_empty() {
+        return Color::WHITE;
+    }
+    //a period of 0 (or an infinite one) stays on the first color
+    let amount = if amount.is_finite() { amount.rem_euclid(1.0) } else { 0.0 };
+
+    let position = amount * colors.len() as f32;
+    let index = (position as usize).min(colors.len() - 1);
+
+    space.mix(
+        colors[index],
+        colors[(index + 1) % colors.len()],
+        position - index as f32,
+    )
+}
+
+///Color at the given amount (0 to 1) from the first color to the last
+fn spread_colors(colors: &[Color], space: ColorSpace, amount: f32) -> Color {
+    match colors {
+        [] => Color::WHITE,
+        [color] => *color,
+        _ => {
+            let position = amount.clamp(0.0, 1.0) * (colors.len() - 1) as f32;
+            let index = (position as usize).min(colors.len() - 2);
+
+            space.mix(colors[index], colors[index + 1], position - index as f32)
+        }
+    }
+}
+
+///Smooth random value (0 to 1) that changes about once per unit
+fn value_noise(position: HexCoord, seed: u32) -> f32 {
+    if !(position.0.is_finite() && position.1.is_finite()) {
+        return 0.0;
+    }
+
+    let (x, y) = (position.0.floor(), position.1.floor());
+    let (cell_x, cell_y) = (x as i32, y as i32);
+
+    //eases between the corners so there aren't any visible creases along the cells
+    let fade = |value: f32| value * value * value * (value * (value * 6.0 - 15.0) + 10.0);
+    let (fade_x, fade_y) = (fade(position.0 - x), fade(position.1 - y));
+
+    let lerp = |a: f32, b: f32, amount: f32| a + (b - a) * amount;
+
+    lerp(
+        lerp(hash(cell_x, cell_y, seed), hash(cell_x + 1, cell_y, seed), fade_x),
+        lerp(hash(cell_x, cell_y + 1, seed), hash(cell_x + 1, cell_y + 1, seed), fade_x),
+        fade_y,
+    )
+}
+
+///Random value (0 to 1) for a pair of numbers (eg. a corner of the noise)
+pub(super) fn hash(x: i32, y: i32, seed: u32) -> f32 {
+    let mut hash = seed
+        ^ (x as u32).wrapping_mul(0x27d4_eb2d)
+        ^ (y as u32).wrapping_mul(0x1656_67b1).rotate_left(16);
+
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^= hash >> 16;
+
+    hash as f32 / u32::MAX as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grids::{GridDraw, HexGrid},
+        options::{GridOptions, GridPatternOptions, Intersections, Lines, Point},
+    };
+
+    use super::*;
+
+    const RED: Color = Color(255, 0, 0, 255);
+    const GREEN: Color = Color(0, 255, 0, 255);
+    const BLUE: Color = Color(0, 0, 255, 255);
+
+    ///Whether every channel is within the tolerance of the other color
+    fn close(a: Color, b: Color, tolerance: u8) -> bool {
+        [(a.0, b.0), (a.1, b.1), (a.2, b.2), (a.3, b.3)]
+            .iter()
+            .all(|(a, b)| a.abs_diff(*b) <= tolerance)
+    }
+
+    #[test]
+    fn fallbacks() {
+        let position = HexCoord(1.3, 2.7);
+        let pigments = [
+            Pigment::Linear { angle: 30.0, period: 2.0 },
+            Pigment::Radial { center: HexCoord(0.0, 0.0), period: 2.0 },
+            Pigment::Noise { scale: 2.0, seed: 1 },
+        ];
+
+        for pigment in pigments {
+            //no colors is white
+            assert_eq!(pigment.color_at(&[], ColorSpace::Srgb, position), Color::WHITE);
+            //non-finite positions are the first color
+            for position in [HexCoord(f32::NAN, 0.0), HexCoord(0.0, f32::INFINITY)] {
+                assert_eq!(pigment.color_at(&[RED, BLUE], ColorSpace::Srgb, position), RED, "{pigment:?}");
+            }
+        }
+
+        //a period of 0 stays on the first color
+        for pigment in [
+            Pigment::Linear { angle: 30.0, period: 0.0 },
+            Pigment::Radial { center: HexCoord(0.0, 0.0), period: 0.0 },
+        ] {
+            assert_eq!(pigment.color_at(&[RED, BLUE], ColorSpace::Srgb, position), RED, "{pigment:?}");
+            assert_eq!(pigment.color_at(&[RED, BLUE], ColorSpace::Srgb, HexCoord(0.0, 0.0)), RED);
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        let colors = [RED, GREEN, BLUE];
+        let cycle = |amount| cycle_colors(&colors, ColorSpace::Srgb, amount);
+
+        assert_eq!(cycle(0.0), RED);
+        assert_eq!(cycle(1.0 / 3.0), GREEN);
+        assert_eq!(cycle(2.0 / 3.0), BLUE);
+        //each period starts over at the first color
+        assert_eq!(cycle(1.0), RED);
+        assert_eq!(cycle(5.0), RED);
+        //and it goes back to the first color after the last one
+        assert!(close(cycle(5.0 / 6.0), RED.mix(BLUE, 0.5), 1));
+        //negative amounts keep cycling the same way
+        assert_eq!(cycle(-1.0), RED);
+        assert_eq!(cycle(-1.0 / 3.0), BLUE);
+        assert!(close(cycle(-0.25), cycle(0.75), 1));
+    }
+
+    #[test]
+    fn spread() {
+        let colors = [RED, GREEN, BLUE];
+        let spread = |amount| spread_colors(&colors, ColorSpace::Srgb, amount);
+
+        assert_eq!(spread(0.0), RED);
+        assert_eq!(spread(0.5), GREEN);
+        assert_eq!(spread(1.0), BLUE);
+        //amounts outside of 0 to 1 stay on the ends
+        assert_eq!(spread(-0.5), RED);
+        assert_eq!(spread(3.0), BLUE);
+
+        assert_eq!(spread_colors(&[GREEN], ColorSpace::Srgb, 0.3), GREEN);
+        assert_eq!(spread_colors(&[], ColorSpace::Srgb, 0.3), Color::WHITE);
+    }
+
+    #[test]
+    fn linear_and_radial() {
+        let colors = [RED, BLUE];
+        let linear = Pigment::Linear { angle: 0.0, period: 4.0 };
+        let radial = Pigment::Radial { center: HexCoord(1.0, 1.0), period: 4.0 };
+
+        assert_eq!(linear.color_at(&colors, ColorSpace::Srgb, HexCoord(2.0, 9.0)), BLUE);
+        assert_eq!(linear.color_at(&colors, ColorSpace::Srgb, HexCoord(-2.0, 0.0)), BLUE);
+        assert_eq!(linear.color_at(&colors, ColorSpace::Srgb, HexCoord(4.0, -3.0)), RED);
+
+        assert_eq!(radial.color_at(&colors, ColorSpace::Srgb, HexCoord(1.0, 1.0)), RED);
+        assert_eq!(radial.color_at(&colors, ColorSpace::Srgb, HexCoord(1.0, 3.0)), BLUE);
+        assert_eq!(radial.color_at(&colors, ColorSpace::Srgb, HexCoord(-1.0, 1.0)), BLUE);
+    }
+
+    #[test]
+    fn grid_shares_field() {
+        let pattern = Pattern::try_from("EAST ww").unwrap();
+        let grid = HexGrid::new_normal(vec![pattern.clone(), pattern], 10).unwrap();
+
+        let colors = [RED, BLUE];
+        let pigment = Pigment::Linear { angle: 0.0, period: 20.0 };
+        let options = GridOptions::generate(
+            GridPatternOptions::Uniform(
+                Intersections::Nothing,
+                Lines::Pigment {
+                    colors: colors.to_vec(),
+                    pigment,
+                    space: ColorSpace::Srgb,
+                    bent: true,
+                },
+            ),
+            Point::None,
+        );
+
+        let scale = 40.0;
+        let padding = grid.get_max_radius(&options) * 1.1;
+        let pixmap = grid.draw_grid(scale, &options).unwrap();
+
+        let sampled = grid
+            .get_patterns()
+            .iter()
+            .map(|(_, location, local_scale)| {
+                //halfway along the first line of the pattern
+                let position = *location + HexCoord(0.5, 0.0) * *local_scale;
+                let pixel = (position + HexCoord(padding, padding)) * scale;
+                let pixel = pixmap.pixel(pixel.0 as u32, pixel.1 as u32).unwrap();
+                let drawn = Color(pixel.red(), pixel.green(), pixel.blue(), pixel.alpha());
+
+                let expected = pigment.color_at(&colors, ColorSpace::Srgb, position);
+                assert!(close(drawn, expected, 4), "{drawn:?} {expected:?}");
+                drawn
+            })
+            .collect::<Vec<Color>>();
+
+        //the second pattern carries on along the field instead of starting it over
+        assert_eq!(sampled.len(), 2);
+        assert!(!close(sampled[0], sampled[1], 4), "{sampled:?}");
+    }
+}
@@ -1,17 +1,15 @@
 use std::collections::HashMap;
 
-use tiny_skia::{
-    LineCap, LineJoin, Paint, PathBuilder, Pixmap, Stroke, StrokeDash, Transform,
-};
-
 use crate::{
     options::{CollisionOption, Marker, Triangle, Color},
     pattern::text::draw_text,
     pattern_utils::{ConnectionPoint, Coord, HexCoord, LineDrawer},
+    render::{LineCap, LineJoin, LinePaint, LineStyle, RenderTarget},
 };
 
 use super::{
     point::draw_point,
+    progress::split_progress,
     triangle::{draw_triangle, rotate_point},
     Pattern,
 };
@@ -19,14 +17,15 @@ use super::{
 #[allow(clippy::too_many_arguments)]
 pub fn draw_segment_lines(
     pattern: &Pattern,
-    pixmap: &mut Pixmap,
-    stroke: &Stroke,
+    target: &mut dyn RenderTarget,
+    stroke: &LineStyle,
     origin: HexCoord,
     scale: f32,
-    colors: &Vec<Color>,
+    colors: &[Color],
     triangles: &Triangle,
     point_radius: f32,
     collisions: &CollisionOption,
+    progress: f32,
 ) -> Color {
     let point_radius = stroke.width.max(scale * point_radius);
     let mut visited_points: HashMap<Coord, Vec<usize>> = HashMap::new();
@@ -39,9 +38,7 @@ pub fn draw_segment_lines(
 
     let mut last_collision_lane = None;
 
-    let mut paint = Paint::default();
-    paint.set_color(colors[0].into());
-    let mut drawer = LineDrawer::new(origin, stroke.clone(), paint);
+    let mut drawer = LineDrawer::new(origin, stroke.clone(), LinePaint::Solid(colors[0]));
 
     let mut prev_loc = origin;
     let mut prev_point = Coord(0, 0);
@@ -81,15 +78,19 @@ pub fn draw_segment_lines(
     };
 
     let mut collision_stroke = stroke.clone();
-    collision_stroke.dash = StrokeDash::new(vec![scale / 18.0, scale / 16.0], 0.0);
-    collision_stroke.line_cap = LineCap::Butt;
-    collision_stroke.line_join = LineJoin::Bevel;
+    collision_stroke.dash = Some(vec![scale / 18.0, scale / 16.0]);
+    collision_stroke.cap = LineCap::Butt;
+    collision_stroke.join = LineJoin::Bevel;
 
     let mut ended_on_collision = false;
 
     let mut visited: HashMap<ConnectionPoint, (i32, Coord)> = HashMap::new();
 
-    for i in 0..pattern.path.len() {
+    let (full, partial) = split_progress(pattern.path.len() - 1, progress);
+    let drawn = if partial > 0.0 { full + 1 } else { full };
+
+    for i in 0..=drawn {
+        let is_partial = i > full;
         let point = &pattern.path[i];
         let loc = origin + HexCoord::from(*point) * scale;
 
@@ -138,26 +139,40 @@ pub fn draw_segment_lines(
             (start, end, line_width / stroke.width)
         };
 
+        //only part of the last segment is drawn when it's partially complete
+        let (end, full_end) = if is_partial {
+            (start + (end - start) * partial, end)
+        } else {
+            (end, end)
+        };
+        let clip = |a: HexCoord, b: HexCoord| {
+            if is_partial {
+                clip_line(a, b, prev_loc, loc, partial)
+            } else {
+                Some((a, b))
+            }
+        };
+
         let not_draw_red = full_dash && visited_count > 0;
         let not_draw_lines = collisions >= too_many_lines;
         let not_draw_stripes = stripes && collisions >= too_many_lines;
         let draw = !not_draw_red && !not_draw_lines && !not_draw_stripes;
 
 
-        if draw && visited_colors.contains(&cur_color) {
-            let middle = (end - start) / 2.0 + start;
+        if draw && visited_colors.contains(&cur_color) && (!is_partial || partial >= 0.5) {
+            let middle = (full_end - start) / 2.0 + start;
 
             if let Some(marker) =
                 triangles.to_middle_point(*colors.get(cur_color).unwrap_or(&bad_color))
             {
-                triangle_queue.push((marker, middle, end, triangle_scale));
+                triangle_queue.push((marker, middle, full_end, triangle_scale));
             }
 
             drawer.line_to(middle);
 
             cur_color = get_next_color(cur_color, visited_colors, colors.len());
 
-            drawer.set_color(colors[cur_color].into());
+            drawer.set_color(colors[cur_color]);
         }
 
         if draw {
@@ -181,36 +196,40 @@ pub fn draw_segment_lines(
             let end_seg = unit_vec * end_offset + start;
 
             let mut stroke = stroke.clone();
-            stroke.line_cap = LineCap::Butt;
-            stroke.line_join = LineJoin::Miter;
+            stroke.cap = LineCap::Butt;
+            stroke.join = LineJoin::Miter;
 
             drawer.set_stroke(stroke);
 
             if visited_count == 0 {
                 let start_set = start + unit_vec * (point_radius + segment_length);
-                drawer.move_to(start);
-                drawer.line_to(start_set);
+                if let Some((start, start_set)) = clip(start, start_set) {
+                    drawer.move_to(start);
+                    drawer.line_to(start_set);
+                }
             }
             if visited_colors.contains(&cur_color) {
                 cur_color = get_next_color(cur_color, visited_colors, colors.len());
             }
-            drawer.move_to(start_seg);
-            drawer.set_color(colors[cur_color].into());
+            if let Some((start_seg, end_seg)) = clip(start_seg, end_seg) {
+                drawer.move_to(start_seg);
+                drawer.set_color(colors[cur_color]);
 
-            drawer.line_to(end_seg);
+                drawer.line_to(end_seg);
+            }
         } else if (full_dash && collisions > 0 || collisions >= too_many_lines)
             && !visited.contains_key(&connection_point)
         {
             drawer.set_stroke(collision_stroke.clone());
-            drawer.set_color(bad_color.into());
+            drawer.set_color(bad_color);
             drawer.move_to(prev_loc);
-            drawer.line_to(loc);
+            drawer.line_to(prev_loc + (loc - prev_loc) * if is_partial { partial } else { 1.0 });
             drawer.priority_finish();
-            drawer.set_color(colors[cur_color].into());
+            drawer.set_color(colors[cur_color]);
 
-            if collisions >= too_many_lines && !full_dash {
+            if collisions >= too_many_lines && !full_dash && !is_partial {
                 if let Some(label) = label {
-                    draw_label(pixmap, label, prev_loc, loc, stroke, scale, collisions);
+                    draw_label(target, label, prev_loc, loc, stroke, scale, collisions);
                 }
             }
         }
@@ -235,10 +254,10 @@ pub fn draw_segment_lines(
         prev_point = *point;
     }
 
-    drawer.draw(pixmap);
+    drawer.draw(target);
 
     for (triangle, location, next, scaler) in triangle_queue {
-        draw_triangle(triangle, pixmap, location, next, scale * scaler);
+        draw_triangle(triangle, target, location, next, scale * scaler);
     }
 
     let cur_loc = origin + HexCoord::from(pattern.path[1]) * scale;
@@ -246,9 +265,11 @@ pub fn draw_segment_lines(
     let mid_point = (cur_loc - prev_loc) / 2.0 + prev_loc;
 
     if let Some(marker) = triangles.to_start_point(colors[0]) {
-        draw_triangle(marker, pixmap, mid_point, cur_loc, scale);
+        if full > 0 || partial >= 0.5 {
+            draw_triangle(marker, target, mid_point, cur_loc, scale);
+        }
     }
-    drawer.draw_priority(pixmap);
+    drawer.draw_priority(target);
 
     if !ended_on_collision {
         colors[cur_color]
@@ -257,7 +278,7 @@ pub fn draw_segment_lines(
     }
 }
 
-fn get_next_color(cur_color: usize, visited: &Vec<usize>, color_count: usize) -> usize {
+fn get_next_color(cur_color: usize, visited: &[usize], color_count: usize) -> usize {
     if visited.len() >= color_count {
         (cur_color + 1) % color_count
     } else {
@@ -334,12 +355,36 @@ fn calculate_start_end(
     (start, end, line_width)
 }
 
+///Clips the line from a to b to the part of the segment (seg_start to seg_end) that has been drawn
+/// amount is how much of the segment has been drawn (0-1)
+fn clip_line(
+    a: HexCoord,
+    b: HexCoord,
+    seg_start: HexCoord,
+    seg_end: HexCoord,
+    amount: f32,
+) -> Option<(HexCoord, HexCoord)> {
+    let dir = seg_end - seg_start;
+    let length = dir.0 * dir.0 + dir.1 * dir.1;
+    let along = |point: HexCoord| {
+        ((point.0 - seg_start.0) * dir.0 + (point.1 - seg_start.1) * dir.1) / length
+    };
+    let cut = seg_start + dir * amount;
+
+    match (along(a) > amount, along(b) > amount) {
+        (true, true) => None,
+        (true, false) => Some((cut, b)),
+        (false, true) => Some((a, cut)),
+        (false, false) => Some((a, b)),
+    }
+}
+
 fn draw_label(
-    pixmap: &mut Pixmap,
+    target: &mut dyn RenderTarget,
     label: &Marker,
     prev_loc: HexCoord,
     loc: HexCoord,
-    stroke: &Stroke,
+    stroke: &LineStyle,
     scale: f32,
     collisions: i32,
 ) {
@@ -353,29 +398,20 @@ fn draw_label(
     let point = rotate_point(middle, middle + offset, -90f32.to_radians());
     let line_point = rotate_point(middle, middle + line_offset, -90f32.to_radians());
 
-    let mut paint = Paint::default();
-    paint.set_color(label.color.into());
-
-    let stroke = Stroke {
+    let stroke = LineStyle {
         width: radius * 2.0,
-        line_cap: LineCap::Butt,
-        ..Default::default()
+        cap: LineCap::Butt,
+        join: LineJoin::Miter,
+        dash: None,
     };
-    //stroke.width = radius * 2.0;
-    //stroke.line_cap = LineCap::Butt;
-
-    let mut path = PathBuilder::new();
-    path.move_to(line_point.0, line_point.1);
-    path.line_to(point.0, point.1);
-    let path = path.finish().unwrap();
 
-    pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+    target.stroke_path(&[line_point, point], &stroke, &LinePaint::Solid(label.color));
 
-    draw_point(pixmap, point, radius, label.color);
+    draw_point(target, point, radius, label.color);
     draw_text(
-        pixmap,
+        target,
         &format!("{collisions}"),
-        Color::BLACK.into(),
+        Color::BLACK,
         point,
         radius,
     );
@@ -0,0 +1,154 @@
+use crate::options::Color;
+use crate::pattern_utils::{HexCoord, LineDrawer};
+use crate::render::{LinePaint, LineStyle, RenderTarget};
+
+use super::{draw_pigment::hash, progress::split_progress, Pattern};
+
+///Width of the core in relation to the width of the whole line
+const CORE_WIDTH: f32 = 0.4;
+
+///Most pieces a segment gets split into (higher frequencies are clamped to this)
+/// anything past a few hundred can't be seen anyways and would only take up memory
+const MAX_PIECES: usize = 256;
+
+///Draws the lines as jittery lightning (a halo with a thinner core on top)
+#[allow(clippy::too_many_arguments)]
+pub fn draw_zappy_lines(
+    pattern: &Pattern,
+    target: &mut dyn RenderTarget,
+    stroke: &LineStyle,
+    origin: HexCoord,
+    scale: f32,
+    core: Color,
+    halo: Color,
+    amplitude: f32,
+    frequency: f32,
+    seed: u32,
+    progress: f32,
+) {
+    let points = get_zappy_points(pattern, origin, scale, amplitude, frequency, seed, progress);
+
+    for (width, color) in [(stroke.width, halo), (stroke.width * CORE_WIDTH, core)] {
+        let stroke = LineStyle {
+            width,
+            ..stroke.clone()
+        };
+        let mut line_drawer = LineDrawer::new(points[0], stroke, LinePaint::Solid(color));
+        for point in &points[1..] {
+            line_drawer.line_to(*point);
+        }
+        line_drawer.draw_all(target);
+    }
+}
+
+///Points (in pixels) that the jittery line goes through
+/// The points of the pattern stay where they are, only the lines between them get pushed to the sides
+/// The line stops partway through a segment depending on the progress
+fn get_zappy_points(
+    pattern: &Pattern,
+    origin: HexCoord,
+    scale: f32,
+    amplitude: f32,
+    frequency: f32,
+    seed: u32,
+    progress: f32,
+) -> Vec<HexCoord> {
+    let (full, partial) = split_progress(pattern.path.len() - 1, progress);
+    let drawn = if partial > 0.0 { full + 1 } else { full };
+
+    let pieces = if frequency.is_finite() {
+        frequency.round().clamp(0.0, (MAX_PIECES - 1) as f32) as usize + 1
+    } else {
+        1
+    };
+    let amplitude = if amplitude.is_finite() { amplitude } else { 0.0 };
+
+    let mut points = vec![origin + HexCoord::from(pattern.path[0]) * scale];
+
+    for segment in 0..drawn {
+        let start = origin + HexCoord::from(pattern.path[segment]) * scale;
+        let end = origin + HexCoord::from(pattern.path[segment + 1]) * scale;
+
+        //segments are scale long, so this is the distance between points to the side of it
+        let side = HexCoord(start.1 - end.1, end.0 - start.0);
+
+        let amount = if segment == full { partial } else { 1.0 };
+
+        let mut prev = start;
+        for piece in 1..=pieces {
+            let point = if piece == pieces {
+                end
+            } else {
+                let jitter = hash(segment as i32, piece as i32, seed) * 2.0 - 1.0;
+                start + (end - start) * (piece as f32 / pieces as f32) + side * (amplitude * jitter)
+            };
+
+            let reached = piece as f32 / pieces as f32;
+            if reached >= amount {
+                let prev_reached = (piece - 1) as f32 / pieces as f32;
+                points.push(prev + (point - prev) * ((amount - prev_reached) / (reached - prev_reached)));
+                break;
+            }
+            points.push(point);
+            prev = point;
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use tiny_skia::Pixmap;
+
+    use crate::options::{Intersections, Lines, Point};
+
+    use super::*;
+
+    fn draw(lines: &Lines) -> Pixmap {
+        let pattern = Pattern::try_from("EAST qaqwedd").unwrap();
+        let mut pixmap = Pixmap::new(400, 400).unwrap();
+        pattern.draw_pattern(
+            &mut pixmap,
+            HexCoord(200.0, 200.0),
+            50.0,
+            0.12,
+            lines,
+            &Intersections::Nothing,
+            &Point::None,
+            1.0,
+        );
+        pixmap
+    }
+
+    fn zappy(seed: u32) -> Lines {
+        Lines::Zappy {
+            core: Color(255, 255, 255, 255),
+            halo: Color(100, 0, 200, 255),
+            amplitude: 0.2,
+            frequency: 6.0,
+            seed,
+        }
+    }
+
+    #[test]
+    fn same_seed_same_lines() {
+        assert!(draw(&zappy(3)) == draw(&zappy(3)));
+    }
+
+    #[test]
+    fn different_seed_different_lines() {
+        assert!(draw(&zappy(3)) != draw(&zappy(4)));
+    }
+
+    #[test]
+    fn frequency_is_clamped() {
+        let pattern = Pattern::try_from("EAST qaq").unwrap();
+        let segments = pattern.path.len() - 1;
+
+        for frequency in [1e9, f32::MAX] {
+            let points = get_zappy_points(&pattern, HexCoord(0.0, 0.0), 1.0, 0.1, frequency, 0, 1.0);
+            assert_eq!(points.len(), segments * MAX_PIECES + 1);
+        }
+    }
+}
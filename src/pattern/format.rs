@@ -0,0 +1,53 @@
+use std::fmt::{self, Display};
+
+use super::Pattern;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///Ways of writing a pattern as text
+pub enum PatternFormat {
+    ///HexPattern(DIRECTION angle_sig) -- the format used ingame (eg. HexPattern(EAST qaq))
+    HexPattern,
+    ///DIRECTION angle_sig -- without the HexPattern wrapper (eg. EAST qaq)
+    Short,
+    ///Just the angle_sig (eg. qaq), which loses the start direction
+    AngleSig,
+}
+
+///Pattern written in a given [PatternFormat], created with [Pattern::display]
+pub struct PatternDisplay<'a> {
+    pattern: &'a Pattern,
+    format: PatternFormat,
+}
+
+impl Pattern {
+    ///Formats the pattern as text in the given [PatternFormat]
+    /// Both [PatternFormat::HexPattern] and [PatternFormat::Short] can be parsed back with [Pattern::try_from]
+    pub fn display(&self, format: PatternFormat) -> PatternDisplay<'_> {
+        PatternDisplay {
+            pattern: self,
+            format,
+        }
+    }
+}
+
+impl Display for PatternDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction: &str = self.pattern.start_direction.into();
+        let angle_sig: String = self.pattern.angles.iter().map(|angle| char::from(*angle)).collect();
+
+        match (self.format, angle_sig.is_empty()) {
+            (PatternFormat::HexPattern, true) => write!(f, "HexPattern({direction})"),
+            (PatternFormat::HexPattern, false) => write!(f, "HexPattern({direction} {angle_sig})"),
+            (PatternFormat::Short, true) => write!(f, "{direction}"),
+            (PatternFormat::Short, false) => write!(f, "{direction} {angle_sig}"),
+            (PatternFormat::AngleSig, _) => write!(f, "{angle_sig}"),
+        }
+    }
+}
+
+impl Display for Pattern {
+    ///Writes the pattern as HexPattern(DIRECTION angle_sig)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(PatternFormat::HexPattern).fmt(f)
+    }
+}
@@ -1,10 +1,36 @@
 mod pattern_internal;
 pub use pattern_internal::*;
 
+mod format;
+pub use format::{PatternDisplay, PatternFormat};
+
+mod bookkeeper;
+pub use bookkeeper::MaskParseError;
+
+mod number;
+pub use number::NumberEncodeError;
+
+mod shape;
+pub use shape::ShapeKey;
+
+mod stats;
+pub use stats::PatternStats;
+
+mod transform;
+
+mod validate;
+pub use validate::PatternIssue;
+
+mod stroke;
+pub use stroke::StrokeError;
+
 mod point;
+mod progress;
 mod triangle;
 
 mod draw_gradient;
 mod draw_monocolor;
+mod draw_pigment;
 mod draw_segments;
+mod draw_zappy;
 mod text;
@@ -0,0 +1,486 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::pattern_utils::{Angle, ConnectionPoint, Coord, Direction};
+
+use super::Pattern;
+
+///Angles at the start of a Numerical Reflection for a positive number (aqaa)
+const POSITIVE_PREFIX: [Angle; 4] = [Angle::BackLeft, Angle::Left, Angle::BackLeft, Angle::BackLeft];
+///Angles at the start of a Numerical Reflection for a negative number (dedd)
+const NEGATIVE_PREFIX: [Angle; 4] = [Angle::BackRight, Angle::Right, Angle::BackRight, Angle::BackRight];
+
+impl Pattern {
+    ///Whether the pattern is a Numerical Reflection (starts with aqaa or dedd and has a valid number after it)
+    pub fn is_number(&self) -> bool {
+        self.number_value().is_some()
+    }
+
+    ///Value pushed by the pattern if it's a Numerical Reflection
+    /// The number starts at 0 and each angle after the prefix changes it the same way it does ingame:
+    /// w adds 1, q adds 5, e adds 10, a doubles it and d halves it
+    /// (the value is negated if the prefix is dedd)
+    pub fn number_value(&self) -> Option<f64> {
+        let (negative, angles) = if let Some(angles) = self.angles.strip_prefix(&POSITIVE_PREFIX) {
+            (false, angles)
+        } else if let Some(angles) = self.angles.strip_prefix(&NEGATIVE_PREFIX) {
+            (true, angles)
+        } else {
+            return None;
+        };
+
+        let value = angles.iter().try_fold(0.0, |value, angle| match angle {
+            Angle::Forward => Some(value + 1.0),
+            Angle::Left => Some(value + 5.0),
+            Angle::Right => Some(value + 10.0),
+            Angle::BackLeft => Some(value * 2.0),
+            Angle::BackRight => Some(value / 2.0),
+            Angle::Back => None,
+        })?;
+
+        //avoids showing dedd as -0
+        Some(if negative && value != 0.0 { -value } else { value })
+    }
+}
+
+///Largest amount worth adding at one level when working out the shortest length
+/// (adding 20 takes 2 angles but adding 10 a level higher only takes 1)
+const MAX_ADDED: u32 = 20;
+
+///Most steps the search takes before giving up (a fraction of a second, enough for values up to around 100000)
+/// Each value tried and each amount checked while working out a lower bound counts as a step
+/// Values with long fractions (eg. 0.1, which is stored as a sum of over 50 halves) would otherwise search for minutes
+const SEARCH_STEPS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberEncodeError {
+    ///The value is NaN or infinite
+    NotFinite(f64),
+    ///There's no Numerical Reflection for the value within the max length (including the 4 angle prefix)
+    TooLong { value: f64, max_length: usize },
+    ///The search gave up after a million steps without finding a Numerical Reflection within the max length
+    /// (there may still be one, but finding it would take too long)
+    SearchLimit { value: f64, max_length: usize },
+}
+
+impl Pattern {
+    ///Creates the Numerical Reflection that pushes the given value
+    /// Uses the shortest signature that doesn't draw over any of its own lines
+    /// (the pattern always starts SOUTH_EAST like the ones generated ingame)
+    /// Every shorter length is ruled out first, which takes longer the bigger or more precise the value is,
+    /// so the search gives up with [NumberEncodeError::SearchLimit] after a million steps (eg. for 1e6 or 0.1)
+    /// * value - Number to encode
+    /// * max_length - Maximum number of angles in the signature (including the aqaa/dedd prefix)
+    pub fn from_number(value: f64, max_length: usize) -> Result<Pattern, NumberEncodeError> {
+        if !value.is_finite() {
+            return Err(NumberEncodeError::NotFinite(value));
+        }
+        let too_long = NumberEncodeError::TooLong { value, max_length };
+        let gave_up = NumberEncodeError::SearchLimit { value, max_length };
+
+        let (prefix, target) = if value < 0.0 {
+            (NEGATIVE_PREFIX, -value)
+        } else {
+            (POSITIVE_PREFIX, value)
+        };
+
+        let max_length = max_length.checked_sub(prefix.len()).ok_or(too_long)?;
+
+        let mut search = NumberSearch {
+            costs: HashMap::new(),
+            prefix,
+            angles: Vec::new(),
+            walk: ReverseWalk::new(),
+            steps_left: SEARCH_STEPS,
+        };
+
+        //tries each length in turn (starting from the least it could possibly be)
+        //so the first one found is the shortest
+        let found = (search.cost(target)..=max_length).find(|length| search.search(target, *length));
+        if found.is_none() {
+            return Err(if search.steps_left == 0 { gave_up } else { too_long });
+        }
+
+        let angles = prefix.into_iter().chain(search.angles.into_iter().rev()).collect();
+        Ok(Pattern::new(Direction::SouthEast, angles))
+    }
+}
+
+///Search for the angles of a number
+/// It works backwards from the target value (undoing one angle at a time) until it reaches 0
+struct NumberSearch {
+    ///Length of the shortest angles that reach each value from 0 (ignoring overlapping lines)
+    costs: HashMap<u64, usize>,
+    prefix: [Angle; 4],
+    ///Angles found so far (from the end of the pattern backwards)
+    angles: Vec<Angle>,
+    walk: ReverseWalk,
+    ///Steps the search can still take before giving up
+    steps_left: usize,
+}
+
+impl NumberSearch {
+    ///Finds angles to reach the value with at most the given number of angles
+    /// (always false once it runs out of steps)
+    fn search(&mut self, value: f64, remaining: usize) -> bool {
+        if self.steps_left == 0 {
+            return false;
+        }
+        self.steps_left -= 1;
+
+        if value == 0.0 {
+            return self.walk.push_prefix(&self.prefix);
+        }
+        if self.cost(value) > remaining {
+            return false;
+        }
+
+        let mut moves = undo_moves(value);
+        moves.sort_by_cached_key(|(value, _)| self.cost(*value));
+
+        for (previous, angle) in moves {
+            if !self.walk.push(angle) {
+                continue;
+            }
+            self.angles.push(angle);
+
+            if self.search(previous, remaining - 1) {
+                return true;
+            }
+
+            self.angles.pop();
+            self.walk.pop();
+        }
+
+        false
+    }
+
+    ///Least number of angles needed to reach the value from 0 (ignoring overlapping lines)
+    /// Everything added with w, q and e gets doubled or halved by each a and d after it,
+    /// so the value is a sum of amounts added at different levels (powers of 2)
+    /// and the a and d angles walk from the first level to the others and then back to level 0.
+    /// This goes up the levels from the lowest one, finding the cheapest amounts to add at each one
+    /// and checking the total length if the rest of the value is added at that level.
+    fn cost(&mut self, value: f64) -> usize {
+        if value == 0.0 {
+            return 0;
+        }
+        if let Some(cost) = self.costs.get(&value.to_bits()) {
+            return *cost;
+        }
+
+        let mut best = usize::MAX;
+
+        let (fraction_bits, whole) = to_whole(value);
+        for (lowest, scaled) in [(fraction_bits, whole), (fraction_bits + 1, whole * 2.0)] {
+            if !scaled.is_finite() {
+                continue;
+            }
+            let lowest_level = -(lowest as i32);
+
+            //amount left to add (in units of the current level) mapped to the angles used so far
+            let mut left_to_add = HashMap::from([(scaled.to_bits(), 0)]);
+            let mut level = lowest_level;
+
+            while !left_to_add.is_empty() {
+                let mut next = HashMap::new();
+
+                for (left, used) in left_to_add {
+                    let left = f64::from_bits(left);
+
+                    let total = additions(left)
+                        .saturating_add(used)
+                        .saturating_add(walk_length(lowest_level, level.max(0)));
+                    best = best.min(total);
+
+                    //working out the bound for huge or very precise values takes a while too
+                    self.steps_left = self.steps_left.saturating_sub(1);
+                    for added in (0..MAX_ADDED).filter(|added| (*added % 2) as f64 == left % 2.0) {
+                        let rest = left - added as f64;
+                        //stops once everything was added (or when big values lose precision)
+                        if rest <= 0.0 || rest + added as f64 != left {
+                            continue;
+                        }
+
+                        let used = used + additions(added as f64);
+                        let entry = next.entry((rest / 2.0).to_bits()).or_insert(used);
+                        *entry = used.min(*entry);
+                    }
+                }
+
+                left_to_add = next;
+                level += 1;
+            }
+        }
+
+        self.costs.insert(value.to_bits(), best);
+        best
+    }
+}
+
+///Values (and the angle used) that lead to the given value in one step
+fn undo_moves(value: f64) -> Vec<(f64, Angle)> {
+    let mut moves = Vec::new();
+
+    for (added, angle) in [(10.0, Angle::Right), (5.0, Angle::Left), (1.0, Angle::Forward)] {
+        let previous = value - added;
+        //(as long as they don't lose precision)
+        if previous >= 0.0 && previous + added == value {
+            moves.push((previous, angle));
+        }
+    }
+
+    if (value / 2.0) * 2.0 == value {
+        moves.push((value / 2.0, Angle::BackLeft));
+    }
+    if (value * 2.0).is_finite() {
+        moves.push((value * 2.0, Angle::BackRight));
+    }
+
+    moves
+}
+
+///Doubles the value until it's a whole number
+/// returns the number of times it was doubled along with the whole number
+fn to_whole(value: f64) -> (u32, f64) {
+    let mut bits = 0;
+    let mut value = value;
+    while value.fract() != 0.0 {
+        bits += 1;
+        value *= 2.0;
+    }
+    (bits, value)
+}
+
+///Number of a and d angles needed to visit every level between the lowest and highest
+/// starting at one of them and ending at level 0
+fn walk_length(lowest: i32, highest: i32) -> usize {
+    ((highest - lowest) + highest.min(-lowest)) as usize
+}
+
+///Least number of w, q and e angles that add up to the value
+fn additions(value: f64) -> usize {
+    let tens = (value / 10.0).floor();
+    let ones = value - tens * 10.0;
+    (tens + (ones / 5.0).floor() + ones % 5.0) as usize
+}
+
+///Walks a pattern backwards from its last line to check that none of the lines overlap
+#[derive(Clone)]
+struct ReverseWalk {
+    point: Coord,
+    direction: Direction,
+    lines: HashSet<ConnectionPoint>,
+    history: Vec<(Coord, Direction, ConnectionPoint)>,
+}
+
+impl ReverseWalk {
+    ///Starts with just the last line of the pattern
+    fn new() -> Self {
+        let direction = Direction::East;
+        let point = Coord(0, 0) + direction;
+
+        Self {
+            point,
+            direction,
+            lines: HashSet::from([ConnectionPoint::new(Coord(0, 0), point)]),
+            history: Vec::new(),
+        }
+    }
+
+    ///Adds the line before the current one (the given angle is the turn between them when drawn forwards)
+    /// returns false (without adding it) if the line overlaps one that's already there
+    fn push(&mut self, angle: Angle) -> bool {
+        //turning one way going forwards is turning the other way going backwards
+        let direction = self.direction + angle.mirror();
+        let point = self.point + direction;
+        let line = ConnectionPoint::new(self.point, point);
+
+        if self.lines.contains(&line) {
+            return false;
+        }
+
+        self.lines.insert(line.clone());
+        self.history.push((self.point, self.direction, line));
+        self.point = point;
+        self.direction = direction;
+        true
+    }
+
+    ///Adds the prefix to the start of the pattern
+    /// returns false (without adding any of it) if it overlaps the lines that are already there
+    fn push_prefix(&mut self, prefix: &[Angle]) -> bool {
+        for (added, angle) in prefix.iter().rev().enumerate() {
+            if !self.push(*angle) {
+                for _ in 0..added {
+                    self.pop();
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    ///Removes the last added line
+    fn pop(&mut self) {
+        if let Some((point, direction, line)) = self.history.pop() {
+            self.lines.remove(&line);
+            self.point = point;
+            self.direction = direction;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    const ADDED_ANGLES: [Angle; 5] = [Angle::Forward, Angle::Left, Angle::Right, Angle::BackLeft, Angle::BackRight];
+
+    ///Shortest valid signature length (after the prefix) for each whole number
+    /// found by trying every signature up to the given length
+    fn brute_force_lengths(max_length: usize) -> HashMap<i64, usize> {
+        let mut lengths = HashMap::new();
+        let mut signatures = vec![Vec::new()];
+
+        for length in 0..=max_length {
+            let mut next = Vec::new();
+            for angles in signatures {
+                let pattern = Pattern::new(
+                    Direction::SouthEast,
+                    POSITIVE_PREFIX.iter().chain(&angles).copied().collect(),
+                );
+                if !pattern.is_valid() {
+                    continue;
+                }
+                let value = pattern.number_value().unwrap();
+                if value.fract() == 0.0 {
+                    lengths.entry(value as i64).or_insert(length);
+                }
+                for angle in ADDED_ANGLES {
+                    let mut angles = angles.clone();
+                    angles.push(angle);
+                    next.push(angles);
+                }
+            }
+            signatures = next;
+        }
+
+        lengths
+    }
+
+    #[test]
+    fn from_number_is_shortest() {
+        let max_length = 6;
+        let lengths = brute_force_lengths(max_length);
+
+        for value in 0..=40 {
+            let pattern = Pattern::from_number(value as f64, 30).unwrap();
+            let length = pattern.angles().len() - POSITIVE_PREFIX.len();
+
+            match lengths.get(&value) {
+                Some(shortest) => assert_eq!(length, *shortest, "{value}"),
+                None => assert!(length > max_length, "{value}"),
+            }
+        }
+    }
+
+    #[test]
+    fn from_number_round_trips() {
+        let mut patterns = HashMap::new();
+
+        for value in (-100..=100).map(|value| value as f64 / 4.0) {
+            let pattern = Pattern::from_number(value, 30).unwrap();
+
+            assert!(pattern.is_valid(), "{value}");
+            assert_eq!(pattern.number_value(), Some(value));
+            assert_eq!(patterns.insert(pattern.angles().to_vec(), value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn from_number_errors() {
+        assert_eq!(
+            Pattern::from_number(f64::NAN, 30).map_err(|err| matches!(err, NumberEncodeError::NotFinite(_))),
+            Err(true)
+        );
+        assert_eq!(
+            Pattern::from_number(1234.0, 8),
+            Err(NumberEncodeError::TooLong {
+                value: 1234.0,
+                max_length: 8
+            })
+        );
+        assert_eq!(
+            Pattern::from_number(1.0, 3),
+            Err(NumberEncodeError::TooLong {
+                value: 1.0,
+                max_length: 3
+            })
+        );
+        //0.1 can't be written exactly with halving
+        assert!(Pattern::from_number(0.1, 60).is_err());
+    }
+
+    #[test]
+    fn from_number_gives_up() {
+        //0.1 is stored as a sum of over 50 halves, which would take minutes to search through
+        let start = std::time::Instant::now();
+        assert_eq!(
+            Pattern::from_number(0.1, 100),
+            Err(NumberEncodeError::SearchLimit {
+                value: 0.1,
+                max_length: 100
+            })
+        );
+        assert!(start.elapsed().as_secs() < 60, "took {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn number_value_known_signatures() {
+        let cases = [
+            ("aqaa", Some(0.0)),
+            ("dedd", Some(0.0)),
+            ("aqaaw", Some(1.0)),
+            ("deddw", Some(-1.0)),
+            ("aqaaq", Some(5.0)),
+            ("aqaae", Some(10.0)),
+            ("aqaawa", Some(2.0)),
+            ("aqaawd", Some(0.5)),
+            ("aqaaeaqa", Some(50.0)),
+            ("deddeqwaw", Some(-33.0)),
+            //not a Numerical Reflection
+            ("qaq", None),
+            ("aqa", None),
+            ("waqaa", None),
+            //s (going back) isn't one of the number angles
+            ("aqaaws", None),
+        ];
+
+        for (signature, expected) in cases {
+            let pattern = Pattern::try_from(&format!("EAST {signature}")[..]).unwrap();
+            assert_eq!(pattern.number_value(), expected, "{signature}");
+            assert_eq!(pattern.is_number(), expected.is_some(), "{signature}");
+        }
+    }
+
+    #[test]
+    fn number_value_ignores_start_direction() {
+        for direction in ["EAST", "NORTH_WEST", "SOUTH_WEST"] {
+            let pattern = Pattern::try_from(&format!("{direction} aqaaq")[..]).unwrap();
+            assert_eq!(pattern.number_value(), Some(5.0));
+        }
+    }
+
+    #[test]
+    fn number_value_round_trips_through_signatures() {
+        for signature in ["aqaaw", "deddwa", "aqaaqeqd", "deddewwdd"] {
+            let pattern = Pattern::try_from(&format!("SOUTH_EAST {signature}")[..]).unwrap();
+            let value = pattern.number_value().unwrap();
+            let encoded = Pattern::from_number(value, 30).unwrap();
+            assert_eq!(encoded.number_value(), Some(value), "{signature}");
+            assert!(encoded.angles.len() <= pattern.angles.len(), "{signature}");
+        }
+    }
+}
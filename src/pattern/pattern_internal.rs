@@ -1,18 +1,22 @@
 use std::collections::{HashMap, HashSet};
 
-use tiny_skia::{LineCap, LineJoin, Pixmap, Stroke};
-
 use crate::{
     options::{Intersections, Lines, Point, Color},
     pattern_utils::{
         Angle, AngleParseError, ConnectionPoint, Coord, Direction, DirectionParseError,
         DynamicList, HexCoord,
     },
+    render::{LineStyle, RenderTarget},
 };
 
 use super::{
-    draw_gradient::draw_gradient_lines, draw_monocolor::draw_monocolor_lines,
-    draw_segments::draw_segment_lines, point::draw_points,
+    draw_gradient::draw_gradient_lines,
+    draw_monocolor::draw_monocolor_lines,
+    draw_pigment::draw_pigment_lines,
+    draw_segments::draw_segment_lines,
+    draw_zappy::draw_zappy_lines,
+    point::{draw_point_at, draw_points},
+    progress::split_progress,
 };
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 ///Wrapper around Pattern to specify special cases
@@ -28,6 +32,7 @@ pub enum PatternVariant {
 #[derive(Debug, Clone, PartialEq)]
 ///Represents a pattern to be drawn on a grid
 pub struct Pattern {
+    pub(crate) start_direction: Direction,
     pub(crate) path: Vec<Coord>,
     pub(crate) top_left: Coord,
     pub(crate) bottom_right: Coord,
@@ -64,6 +69,7 @@ impl Pattern {
         let mut top_left_bound = HexCoord::from(path[0]).min_components(path[1].into());
         let mut bottom_right_bound = HexCoord::from(path[0]).max_components(path[1].into());
 
+        let start_direction = rotation;
         let mut rotation = rotation;
 
         let mut left_perimiter = DynamicList::new();
@@ -107,6 +113,7 @@ impl Pattern {
         points.sort();
         points.dedup();
         Pattern {
+            start_direction,
             path,
             top_left,
             bottom_right,
@@ -119,6 +126,47 @@ impl Pattern {
             collisions,
         }
     }
+
+    ///Creates a pattern that goes through the given points in order (the reverse of [Pattern::path])
+    /// Each point has to be next to the one before it on the lattice
+    /// The pattern is moved so it starts at the origin
+    pub fn from_points(points: &[Coord]) -> Result<Self, PointsError> {
+        let directions = points
+            .windows(2)
+            .enumerate()
+            .map(|(index, step)| {
+                Direction::try_from((step[1].0 - step[0].0, step[1].1 - step[0].1)).map_err(
+                    |_| PointsError::NotAdjacent {
+                        index: index + 1,
+                        from: step[0],
+                        to: step[1],
+                    },
+                )
+            })
+            .collect::<Result<Vec<Direction>, PointsError>>()?;
+
+        let start_direction = *directions
+            .first()
+            .ok_or(PointsError::TooFewPoints(points.len()))?;
+
+        let angles = directions
+            .windows(2)
+            .map(|directions| directions[1] - directions[0])
+            .collect();
+
+        Ok(Pattern::new(start_direction, angles))
+    }
+
+    ///Direction of the first line of the pattern
+    pub fn start_direction(&self) -> Direction {
+        self.start_direction
+    }
+
+    ///Angle signature of the pattern (the turns taken after the first line)
+    pub fn angles(&self) -> &[Angle] {
+        &self.angles
+    }
+
     fn add_to_perimiter(
         left_perimiter: &mut DynamicList<Coord>,
         right_perimiter: &mut DynamicList<Coord>,
@@ -140,59 +188,119 @@ impl Pattern {
             right_perimiter.set(point.1, point);
         }
     }
+    ///Draws the first part of the pattern onto any [RenderTarget]
+    /// The line stops partway through a segment (with the end point drawn where it stopped)
+    /// so it can be used to render each frame of the pattern being drawn
+    /// * target - [RenderTarget] to draw the pattern onto
+    /// * origin - Location (in pixels) of the start of the pattern
+    /// * scale - Distance (in pixels) between points
+    /// * line_thickness - Width of the lines as a percentage of scale
+    /// * line_options - [Lines] to draw the pattern with
+    /// * point_options - [Intersections] to draw on the points of the pattern
+    /// * center_dot - [Point] drawn in the middle of the pattern once it's finished
+    /// * progress - How much of the pattern to draw (0 for nothing, 1 for the full pattern)
     #[allow(clippy::too_many_arguments)]
-    pub(crate) fn draw_pattern(
+    pub fn draw_pattern(
         &self,
-        pixmap: &mut Pixmap,
+        target: &mut dyn RenderTarget,
         origin: HexCoord,
         scale: f32,
         line_thickness: f32,
         line_options: &Lines,
         point_options: &Intersections,
         center_dot: &Point,
+        progress: f32,
     ) {
-        let stroke = Stroke {
-            width: line_thickness * scale,
-            line_cap: LineCap::Round,
-            line_join: LineJoin::Round,
-            ..Default::default()
-        };
-        //stroke.width = line_thickness * scale;
-        //stroke.line_cap = LineCap::Round;
-        //stroke.line_join = LineJoin::Round;
+        self.draw_pattern_in_frame(
+            target,
+            origin,
+            scale,
+            (origin, scale),
+            line_thickness,
+            line_options,
+            point_options,
+            center_dot,
+            progress,
+        );
+    }
+
+    ///Same as [Pattern::draw_pattern] except that [Lines::Pigment] is sampled relative to the given frame
+    /// (so every pattern on a grid can share the same one)
+    /// * frame - Location (in pixels) of the origin of the pigment field and the size (in pixels) of one unit of it
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_pattern_in_frame(
+        &self,
+        target: &mut dyn RenderTarget,
+        origin: HexCoord,
+        scale: f32,
+        frame: (HexCoord, f32),
+        line_thickness: f32,
+        line_options: &Lines,
+        point_options: &Intersections,
+        center_dot: &Point,
+        progress: f32,
+    ) {
+        let stroke = LineStyle::round(line_thickness * scale);
 
         let end_colors;
 
         match line_options {
             Lines::Monocolor { color, bent } => {
-                draw_monocolor_lines(self, pixmap, &stroke, origin, scale, *color, *bent);
+                draw_monocolor_lines(self, target, &stroke, origin, scale, *color, *bent, progress);
                 end_colors = (*color, *color);
             }
             Lines::Gradient {
                 colors,
                 segments_per_color,
                 bent,
+                space,
+                easing,
             } => {
                 if colors.len() < 2 {
                     let col = *colors.first().unwrap_or(&Color::WHITE);
                     end_colors = (col, col);
-                    draw_monocolor_lines(self, pixmap, &stroke, origin, scale, col, *bent);
+                    draw_monocolor_lines(self, target, &stroke, origin, scale, col, *bent, progress);
                 } else {
                     end_colors = (
                         colors[0],
                         draw_gradient_lines(
                             self,
-                            pixmap,
+                            target,
                             &stroke,
                             origin,
                             scale,
                             colors,
                             *segments_per_color,
                             *bent,
+                            *space,
+                            *easing,
+                            progress,
                         ),
                     );
                 }
             }
+            Lines::Pigment {
+                colors,
+                pigment,
+                space,
+                bent,
+            } => {
+                end_colors = draw_pigment_lines(
+                    self, target, &stroke, origin, scale, colors, pigment, *space, *bent, frame, progress,
+                );
+            }
+            Lines::Zappy {
+                core,
+                halo,
+                amplitude,
+                frequency,
+                seed,
+            } => {
+                draw_zappy_lines(
+                    self, target, &stroke, origin, scale, *core, *halo, *amplitude, *frequency, *seed, progress,
+                );
+                end_colors = (*core, *core);
+            }
             Lines::SegmentColors {
                 colors,
                 triangles: arrows,
@@ -202,7 +310,7 @@ impl Pattern {
                     colors[0],
                     draw_segment_lines(
                         self,
-                        pixmap,
+                        target,
                         &stroke,
                         origin,
                         scale,
@@ -210,38 +318,63 @@ impl Pattern {
                         arrows,
                         point_options.get_max_radius(),
                         collisions,
+                        progress,
                     ),
                 );
             }
         }
 
+        let (full, partial) = split_progress(self.path.len() - 1, progress);
+        let complete = full == self.path.len() - 1;
+
+        //points that have been reached so far
+        let points = if complete {
+            self.points.clone()
+        } else {
+            let mut points = self.path[..=full].to_vec();
+            points.sort();
+            points.dedup();
+            points
+        };
+
         match point_options {
             Intersections::Nothing => (),
             Intersections::UniformPoints(point) => {
-                draw_points(&self.points, pixmap, origin, scale, point);
+                draw_points(&points, target, origin, scale, point);
             }
             Intersections::EndsAndMiddle { start, end, middle } => {
                 let start_point = self.path[0];
-                let end_point = self.path[self.path.len() - 1];
+                let end_point = self.path[full];
 
                 let start = start.into_point(end_colors.0);
                 let end = end.into_point(end_colors.1);
 
-                draw_points(&vec![start_point], pixmap, origin, scale, &start);
-                if start_point != end_point {
-                    draw_points(&vec![end_point], pixmap, origin, scale, &end);
+                draw_points(&[start_point], target, origin, scale, &start);
+                if partial > 0.0 {
+                    //the end point sits wherever the drawing stopped
+                    let prev = HexCoord::from(end_point);
+                    let next = HexCoord::from(self.path[full + 1]);
+                    let end_loc = origin + (prev + (next - prev) * partial) * scale;
+
+                    draw_point_at(target, end_loc, scale, &end);
+                } else if start_point != end_point {
+                    draw_points(&[end_point], target, origin, scale, &end);
                 }
-                let middle_points: Vec<Coord> = self
-                    .points
-                    .clone()
+                let middle_points: Vec<Coord> = points
                     .into_iter()
-                    .filter(|&point| point != start_point && point != end_point)
+                    .filter(|&point| {
+                        point != start_point && (point != end_point || partial > 0.0)
+                    })
                     .collect();
 
-                draw_points(&middle_points, pixmap, origin, scale, middle);
+                draw_points(&middle_points, target, origin, scale, middle);
             }
         }
 
+        if !complete {
+            return;
+        }
+
         let center = (self.bottom_right_bound + self.top_left_bound) / 2.0;
         let y_factor = 0.866_025_4;
 
@@ -255,8 +388,8 @@ impl Pattern {
             && coord.0 < self.right_perimiter[index].0
         {
             draw_points(
-                &vec![Coord(x_coord, y_coord)],
-                pixmap,
+                &[Coord(x_coord, y_coord)],
+                target,
                 origin,
                 scale,
                 center_dot,
@@ -274,12 +407,22 @@ impl PatternVariant {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointsError {
+    ///There aren't enough points to make a line (a pattern needs at least 2)
+    TooFewPoints(usize),
+    ///The point at the index isn't next to the one before it
+    NotAdjacent { index: usize, from: Coord, to: Coord },
+}
+
 #[derive(Debug, Clone)]
 pub enum PatternParseError {
     InvalidParts(String),
     HangingHexPattern(String),
     InvalidStartDirection { input: String, direction: String },
     InvalidAngle { input: String, angle: char },
+    ///The pattern can't be drawn ingame (only returned by [Pattern::parse_strict])
+    Illegal { input: String, issues: Vec<super::PatternIssue> },
 }
 impl TryFrom<&str> for Pattern {
     type Error = PatternParseError;
@@ -287,6 +430,15 @@ impl TryFrom<&str> for Pattern {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut parts: Vec<&str> = value.trim().split(' ').collect();
 
+        //patterns with no angles are written as HexPattern(DIRECTION) or just DIRECTION
+        if parts.len() == 1 {
+            if parts[0].ends_with(')') {
+                parts = vec![&parts[0][..parts[0].len() - 1], ")"];
+            } else {
+                parts.push("");
+            }
+        }
+
         if parts.len() != 2 {
             return Err(Self::Error::InvalidParts(value.to_string()));
         }
@@ -323,3 +475,58 @@ impl TryFrom<&str> for Pattern {
         Ok(Pattern::new(direction, angles))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_points_round_trip() {
+        for pattern in ["NORTH_EAST qaq", "EAST aawdd", "SOUTH_WEST wqaawdd", "WEST qqqqqaweeeee"] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+            assert_eq!(Pattern::from_points(pattern.path()).unwrap(), pattern);
+
+            //moving the points doesn't change the pattern
+            let moved = pattern
+                .path()
+                .iter()
+                .map(|point| Coord(point.0 + 3, point.1 - 7))
+                .collect::<Vec<Coord>>();
+            assert_eq!(Pattern::from_points(&moved).unwrap(), pattern);
+        }
+    }
+
+    #[test]
+    fn from_points_single_line() {
+        let pattern = Pattern::from_points(&[Coord(0, 0), Coord(0, 1)]).unwrap();
+
+        assert_eq!(pattern.start_direction(), Direction::SouthEast);
+        assert!(pattern.angles().is_empty());
+    }
+
+    #[test]
+    fn from_points_errors() {
+        assert_eq!(Pattern::from_points(&[]), Err(PointsError::TooFewPoints(0)));
+        assert_eq!(
+            Pattern::from_points(&[Coord(2, 2)]),
+            Err(PointsError::TooFewPoints(1))
+        );
+        assert_eq!(
+            Pattern::from_points(&[Coord(0, 0), Coord(1, 0), Coord(3, 0)]),
+            Err(PointsError::NotAdjacent {
+                index: 2,
+                from: Coord(1, 0),
+                to: Coord(3, 0),
+            })
+        );
+        //staying on the same point isn't a line either
+        assert_eq!(
+            Pattern::from_points(&[Coord(0, 0), Coord(0, 0)]),
+            Err(PointsError::NotAdjacent {
+                index: 1,
+                from: Coord(0, 0),
+                to: Coord(0, 0),
+            })
+        );
+    }
+}
@@ -1,54 +1,35 @@
-use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Transform};
-
 use crate::{
     options::{Point, Color},
     pattern_utils::{Coord, HexCoord},
+    render::RenderTarget,
 };
 
 pub fn draw_points(
-    points: &Vec<Coord>,
-    pixmap: &mut Pixmap,
+    points: &[Coord],
+    target: &mut dyn RenderTarget,
     origin: HexCoord,
     scale: f32,
     point: &Point,
 ) {
-    let mut paint = Paint::default();
-    let mut paint2 = Paint::default();
+    for location in points {
+        let loc = HexCoord::from(*location) * scale + origin;
+        draw_point_at(target, loc, scale, point);
+    }
+}
 
+pub fn draw_point_at(target: &mut dyn RenderTarget, loc: HexCoord, scale: f32, point: &Point) {
     match point {
         Point::None => (),
         Point::Single(marker) => {
-            paint.set_color(marker.color.into());
-            for point in points {
-                let loc = HexCoord::from(*point) * scale + origin;
-                let path = PathBuilder::from_circle(loc.0, loc.1, marker.radius * scale).unwrap();
-                pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
-            }
+            target.fill_circle(loc, marker.radius * scale, marker.color);
         }
         Point::Double { inner, outer } => {
-            paint.set_color(outer.color.into());
-            paint2.set_color(inner.color.into());
-            for point in points {
-                let loc = HexCoord::from(*point) * scale + origin;
-                let path = PathBuilder::from_circle(loc.0, loc.1, outer.radius * scale).unwrap();
-                pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
-
-                let path = PathBuilder::from_circle(loc.0, loc.1, inner.radius * scale).unwrap();
-                pixmap.fill_path(
-                    &path,
-                    &paint2,
-                    FillRule::Winding,
-                    Transform::default(),
-                    None,
-                );
-            }
+            target.fill_circle(loc, outer.radius * scale, outer.color);
+            target.fill_circle(loc, inner.radius * scale, inner.color);
         }
     }
 }
 
-pub fn draw_point(pixmap: &mut Pixmap, center: HexCoord, radius: f32, color: Color) {
-    let path = PathBuilder::from_circle(center.0, center.1, radius).unwrap();
-    let mut paint = Paint::default();
-    paint.set_color(color.into());
-    pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
+pub fn draw_point(target: &mut dyn RenderTarget, center: HexCoord, radius: f32, color: Color) {
+    target.fill_circle(center, radius, color);
 }
@@ -0,0 +1,8 @@
+///Splits the progress (0-1) along a path with the given number of segments
+/// into the number of completely drawn segments and how far along the next segment it is (0-1)
+pub fn split_progress(segments: usize, progress: f32) -> (usize, f32) {
+    let cut = progress.clamp(0.0, 1.0) * segments as f32;
+    let full = (cut.floor() as usize).min(segments);
+
+    (full, cut - full as f32)
+}
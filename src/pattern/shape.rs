@@ -0,0 +1,134 @@
+use crate::pattern_utils::{ConnectionPoint, Coord};
+
+use super::Pattern;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///Hashable key of a pattern's shape (the set of lines it's made of)
+/// Two patterns have the same key when they cover the same lines no matter where they start,
+/// which direction they're drawn in or what order the lines are drawn in.
+/// Created with [Pattern::shape_key]
+pub struct ShapeKey(Vec<ConnectionPoint>);
+
+impl Pattern {
+    ///Key of the pattern's shape, used to identify great spells (per world patterns) or dedupe patterns
+    /// * rotation_invariant - Whether patterns that are rotations (by 60 degree steps) of each other get the same key
+    pub fn shape_key(&self, rotation_invariant: bool) -> ShapeKey {
+        let edges = self.get_edges();
+
+        if rotation_invariant {
+            (0..6)
+                .map(|rotation| {
+                    normalize(
+                        edges
+                            .iter()
+                            .map(|(a, b)| (rotate(*a, rotation), rotate(*b, rotation)))
+                            .collect(),
+                    )
+                })
+                .min()
+                .unwrap()
+        } else {
+            normalize(edges)
+        }
+    }
+
+    ///Whether both patterns are made up of the same lines (ignoring start point, direction and stroke order)
+    /// This is how great spells are recognised ingame
+    pub fn same_shape(&self, other: &Pattern) -> bool {
+        self.shape_key(false) == other.shape_key(false)
+    }
+
+    ///Same as [Pattern::same_shape] except that the patterns can also be rotated from each other
+    pub fn same_rotated_shape(&self, other: &Pattern) -> bool {
+        self.shape_key(true) == other.shape_key(true)
+    }
+
+    fn get_edges(&self) -> Vec<(Coord, Coord)> {
+        self.path
+            .windows(2)
+            .map(|points| (points[0], points[1]))
+            .collect()
+    }
+}
+
+///Rotates the point clockwise around the origin by 60 degrees the given number of times
+fn rotate(point: Coord, rotation: u8) -> Coord {
+    (0..rotation).fold(point, |point, _| Coord(-point.1, point.0 + point.1))
+}
+
+///Moves the edges so the smallest point is at the origin then sorts and dedups them
+fn normalize(edges: Vec<(Coord, Coord)>) -> ShapeKey {
+    let Some(min) = edges.iter().flat_map(|(a, b)| [*a, *b]).min() else {
+        return ShapeKey(Vec::new());
+    };
+
+    let mut edges = edges
+        .into_iter()
+        .map(|(a, b)| {
+            ConnectionPoint::new(
+                Coord(a.0 - min.0, a.1 - min.1),
+                Coord(b.0 - min.0, b.1 - min.1),
+            )
+        })
+        .collect::<Vec<ConnectionPoint>>();
+
+    edges.sort();
+    edges.dedup();
+
+    ShapeKey(edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(points: &[(i32, i32)]) -> Pattern {
+        let points = points.iter().map(|(x, y)| Coord(*x, *y)).collect::<Vec<Coord>>();
+        Pattern::from_points(&points).unwrap()
+    }
+
+    #[test]
+    fn reversed_is_same_shape() {
+        for pattern in ["EAST aawdd", "NORTH_EAST qaq", "WEST qqqqqaweeeee"] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+            assert!(pattern.same_shape(&pattern.reversed()));
+            assert_eq!(pattern.shape_key(false), pattern.reversed().shape_key(false));
+        }
+    }
+
+    #[test]
+    fn different_start_and_order() {
+        //a triangle with a tail, drawn starting from the triangle and from the end of the tail
+        let triangle_first = pattern(&[(0, 0), (1, 0), (0, 1), (0, 0), (-1, 0)]);
+        let tail_first = pattern(&[(-1, 0), (0, 0), (0, 1), (1, 0), (0, 0)]);
+        //and starting from a different corner of the triangle
+        let other_corner = pattern(&[(1, 0), (0, 1), (0, 0), (1, 0), (0, 0), (-1, 0)]);
+
+        assert!(triangle_first.same_shape(&tail_first));
+        assert!(triangle_first.same_shape(&other_corner));
+        assert_ne!(triangle_first.angles(), tail_first.angles());
+    }
+
+    #[test]
+    fn rotations() {
+        let pattern = Pattern::try_from("EAST aqaawaa").unwrap();
+
+        for steps in 1..6 {
+            let rotated = pattern.rotated(steps);
+            assert!(pattern.same_rotated_shape(&rotated), "{steps}");
+            assert!(!pattern.same_shape(&rotated), "{steps}");
+        }
+        assert!(pattern.same_shape(&pattern.rotated(6)));
+    }
+
+    #[test]
+    fn mirrored_is_different_shape() {
+        //not symmetric along any line, so no rotation lines it up with its mirror image
+        let pattern = Pattern::try_from("EAST aqaawaa").unwrap();
+        let mirrored = pattern.mirrored();
+
+        assert!(!pattern.same_shape(&mirrored));
+        assert!(!pattern.same_rotated_shape(&mirrored));
+        assert!(!pattern.same_rotated_shape(&mirrored.reversed()));
+    }
+}
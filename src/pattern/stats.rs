@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::pattern_utils::{Coord, HexCoord};
+
+use super::Pattern;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Summary of a pattern's geometry, created with [Pattern::stats]
+pub struct PatternStats {
+    ///Number of lines drawn (one more than the number of angles)
+    pub segments: usize,
+    ///Number of different lines drawn (lines drawn more than once are only counted once)
+    pub unique_segments: usize,
+    ///Number of different lines that are drawn more than once
+    pub overlapping_segments: usize,
+    ///Number of different points the pattern goes through
+    pub unique_points: usize,
+    ///Most times the pattern goes through any one point
+    pub max_visits: usize,
+    ///Top left corner of the pattern on the lattice
+    pub top_left: Coord,
+    ///Bottom right corner of the pattern on the lattice
+    pub bottom_right: Coord,
+    ///Width and height of the pattern in grid units (where points are 1 apart)
+    pub size: HexCoord,
+}
+
+impl Pattern {
+    ///Points the pattern goes through (in the order they're drawn, starting at the origin)
+    pub fn path(&self) -> &[Coord] {
+        &self.path
+    }
+
+    ///Every point the pattern goes through once (sorted)
+    pub fn points(&self) -> &[Coord] {
+        &self.points
+    }
+
+    ///Lines of the pattern as their start and end points (in the order they're drawn)
+    pub fn segments(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.path.windows(2).map(|points| (points[0], points[1]))
+    }
+
+    ///Number of lines drawn (one more than the number of angles)
+    pub fn segment_count(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    ///Number of times lines are drawn over ones that were already drawn
+    /// (a line drawn 3 times counts as 2)
+    pub fn overlap_count(&self) -> usize {
+        self.collisions.values().sum::<i32>() as usize
+    }
+
+    ///Lines that are drawn more than once along with how many extra times they're drawn
+    /// The points of each line are ordered from left to right (and the lines are sorted)
+    pub fn overlaps(&self) -> Vec<((Coord, Coord), usize)> {
+        let mut overlaps = self
+            .collisions
+            .iter()
+            .map(|(line, count)| (line.points(), *count as usize))
+            .collect::<Vec<_>>();
+        overlaps.sort();
+        overlaps
+    }
+
+    ///Number of times the pattern goes through each of its points
+    /// (the start counts as a visit)
+    pub fn visit_counts(&self) -> HashMap<Coord, usize> {
+        let mut visits = HashMap::new();
+        for point in &self.path {
+            *visits.entry(*point).or_insert(0) += 1;
+        }
+        visits
+    }
+
+    ///Top left and bottom right corners of the pattern on the lattice
+    /// (the x and y of each are the smallest and largest of any point)
+    pub fn lattice_bounds(&self) -> (Coord, Coord) {
+        (self.top_left, self.bottom_right)
+    }
+
+    ///Top left and bottom right corners of the pattern in grid units (where points are 1 apart)
+    /// relative to the start of the pattern
+    pub fn bounds(&self) -> (HexCoord, HexCoord) {
+        (self.top_left_bound, self.bottom_right_bound)
+    }
+
+    ///Summary of the pattern's geometry
+    pub fn stats(&self) -> PatternStats {
+        let segments = self.segment_count();
+
+        PatternStats {
+            segments,
+            unique_segments: segments - self.overlap_count(),
+            overlapping_segments: self.collisions.len(),
+            unique_points: self.points.len(),
+            max_visits: self.visit_counts().into_values().max().unwrap_or(0),
+            top_left: self.top_left,
+            bottom_right: self.bottom_right,
+            size: self.bottom_right_bound - self.top_left_bound,
+        }
+    }
+}
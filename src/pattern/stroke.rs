@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use crate::pattern_utils::{ConnectionPoint, Coord, Direction, HexCoord};
+
+use super::Pattern;
+
+///How close (in grid units) the stroke has to get to a point for it to be connected
+const SNAP_RADIUS: f32 = 0.4;
+
+///Largest distance (in grid units) between the locations checked along the stroke
+/// (so fast strokes with few samples don't skip over points)
+const SAMPLE_STEP: f32 = 0.1;
+
+///Most locations checked between two samples of the stroke
+/// (samples that are further apart than this many steps get checked less often instead)
+const MAX_LINE_SAMPLES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StrokeError {
+    ///The scale isn't a positive number
+    InvalidScale(f32),
+    ///The stroke didn't connect any points (it needs to go through at least 2)
+    TooShort,
+}
+
+impl Pattern {
+    ///Creates the pattern drawn by a freehand stroke (eg. mouse or touch input)
+    /// The stroke is read the same way as the staff grid ingame:
+    /// a point is connected once the stroke gets close to it,
+    /// jumps to points that aren't next to the last one and lines that were already drawn are ignored,
+    /// and any jitter between points is ignored as well
+    /// * stroke - Locations (in pixels) the stroke went through in order
+    /// * origin - Location (in pixels) of the point at the origin of the lattice
+    /// * scale - Distance (in pixels) between points
+    pub fn from_stroke(stroke: &[HexCoord], origin: HexCoord, scale: f32) -> Result<Pattern, StrokeError> {
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(StrokeError::InvalidScale(scale));
+        }
+
+        let stroke = stroke
+            .iter()
+            .filter(|location| location.0.is_finite() && location.1.is_finite())
+            .map(|location| (*location - origin) / scale)
+            .collect::<Vec<HexCoord>>();
+
+        let mut points: Vec<Coord> = Vec::new();
+        let mut lines = HashSet::new();
+
+        for location in sample_stroke(&stroke) {
+            let point = location.nearest_coord();
+            if (location - point.into()).magnitude() > SNAP_RADIUS {
+                continue;
+            }
+
+            let Some(last) = points.last().copied() else {
+                points.push(point);
+                continue;
+            };
+
+            if point == last || Direction::try_from((point.0 - last.0, point.1 - last.1)).is_err() {
+                continue;
+            }
+            if lines.insert(ConnectionPoint::new(last, point)) {
+                points.push(point);
+            }
+        }
+
+        Pattern::from_points(&points).map_err(|_| StrokeError::TooShort)
+    }
+}
+
+///Locations along the stroke (adding some between samples that are far apart)
+fn sample_stroke(stroke: &[HexCoord]) -> impl Iterator<Item = HexCoord> + '_ {
+    let lines = stroke.windows(2).flat_map(|line| {
+        let (start, offset) = (line[0], line[1] - line[0]);
+        let steps = (offset.magnitude() / SAMPLE_STEP)
+            .ceil()
+            .max(1.0)
+            .min(MAX_LINE_SAMPLES as f32) as usize;
+
+        (1..=steps).map(move |step| start + offset * (step as f32 / steps as f32))
+    });
+
+    stroke.first().copied().into_iter().chain(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///Stroke (in pixels) through the points of the pattern with a little bit of jitter to the sides
+    fn noisy_stroke(pattern: &Pattern, origin: HexCoord, scale: f32) -> Vec<HexCoord> {
+        let mut stroke = Vec::new();
+        let mut jitter = (0..).map(|index: i32| ((index * 37) % 11) as f32 / 10.0 - 0.5);
+
+        for line in pattern.path().windows(2) {
+            let (start, end) = (HexCoord::from(line[0]), HexCoord::from(line[1]));
+            let side = HexCoord(start.1 - end.1, end.0 - start.0);
+
+            for step in 0..5 {
+                let along = start + (end - start) * (step as f32 / 5.0);
+                let location = along + side * (0.15 * jitter.next().unwrap());
+                stroke.push(origin + location * scale);
+            }
+        }
+        stroke.push(origin + HexCoord::from(*pattern.path().last().unwrap()) * scale);
+
+        stroke
+    }
+
+    #[test]
+    fn noisy_stroke_snaps_to_pattern() {
+        let origin = HexCoord(120.0, 80.0);
+
+        for pattern in ["NORTH_EAST qaq", "EAST aawdd", "SOUTH_WEST wqaawdd"] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+            let stroke = noisy_stroke(&pattern, origin, 40.0);
+
+            assert_eq!(Pattern::from_stroke(&stroke, origin, 40.0), Ok(pattern));
+        }
+    }
+
+    #[test]
+    fn from_stroke_errors() {
+        let stroke = [HexCoord(0.0, 0.0), HexCoord(40.0, 0.0)];
+
+        let origin = HexCoord(0.0, 0.0);
+
+        assert_eq!(Pattern::from_stroke(&stroke, origin, 0.0), Err(StrokeError::InvalidScale(0.0)));
+        assert_eq!(Pattern::from_stroke(&stroke[..1], origin, 40.0), Err(StrokeError::TooShort));
+    }
+
+    #[test]
+    fn long_strokes_are_capped() {
+        let stroke = [HexCoord(0.0, 0.0), HexCoord(1e30, 0.0), HexCoord(f32::MAX, f32::MAX)];
+
+        assert!(sample_stroke(&stroke).count() <= 2 * MAX_LINE_SAMPLES + 1);
+        //only needs to finish, the points that far out aren't next to each other
+        let _ = Pattern::from_stroke(&stroke, HexCoord(0.0, 0.0), 1.0);
+    }
+}
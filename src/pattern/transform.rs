@@ -0,0 +1,89 @@
+use super::Pattern;
+
+impl Pattern {
+    ///Same pattern rotated clockwise by 60 degrees the given number of times
+    /// (negative steps rotate it counter clockwise)
+    /// Only the start direction changes since the angles are relative to it
+    pub fn rotated(&self, steps: i32) -> Pattern {
+        let start_direction = ((self.start_direction as i32 + steps).rem_euclid(6) as u8)
+            .try_into()
+            .unwrap();
+
+        Pattern::new(start_direction, self.angles.clone())
+    }
+
+    ///Mirror image of the pattern (every angle turns the other way)
+    /// It starts in the same direction, so it's mirrored along the line of its first segment
+    pub fn mirrored(&self) -> Pattern {
+        Pattern::new(
+            self.start_direction,
+            self.angles.iter().map(|angle| angle.mirror()).collect(),
+        )
+    }
+
+    ///Same lines drawn from the end of the pattern back to the start
+    pub fn reversed(&self) -> Pattern {
+        let end_direction = self
+            .angles
+            .iter()
+            .fold(self.start_direction, |direction, angle| direction + *angle);
+
+        Pattern::new(
+            end_direction.get_reverse(),
+            self.angles.iter().rev().map(|angle| angle.mirror()).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns() -> Vec<Pattern> {
+        ["NORTH_EAST qaq", "EAST aawdd", "SOUTH_WEST wqaawdd", "WEST qqqqqaweeeee", "SOUTH_EAST a"]
+            .into_iter()
+            .map(|pattern| Pattern::try_from(pattern).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn transforms_undo_themselves() {
+        for pattern in patterns() {
+            assert_eq!(pattern.reversed().reversed(), pattern);
+            assert_eq!(pattern.mirrored().mirrored(), pattern);
+            assert_eq!(pattern.rotated(6), pattern);
+            assert_eq!(pattern.rotated(-6), pattern);
+            assert_eq!(pattern.rotated(2).rotated(-2), pattern);
+        }
+    }
+
+    #[test]
+    fn reversed_keeps_shape() {
+        for pattern in patterns() {
+            let reversed = pattern.reversed();
+            assert!(pattern.same_shape(&reversed), "{pattern} {reversed}");
+            assert_eq!(reversed.angles().len(), pattern.angles().len());
+        }
+    }
+
+    #[test]
+    fn rotated_moves_start() {
+        let pattern = Pattern::try_from("EAST aawdd").unwrap();
+
+        assert_eq!(pattern.rotated(1), Pattern::try_from("SOUTH_EAST aawdd").unwrap());
+        assert_eq!(pattern.rotated(-1), Pattern::try_from("NORTH_EAST aawdd").unwrap());
+        assert_eq!(pattern.mirrored(), Pattern::try_from("EAST ddwaa").unwrap());
+    }
+
+    #[test]
+    fn ingame_reversed_pair() {
+        //Rotation Gambit II is Rotation Gambit drawn backwards
+        let rotation = Pattern::try_from("EAST aaeaa").unwrap();
+        let rotation_ii = Pattern::try_from("NORTH_EAST ddqdd").unwrap();
+
+        let reversed = rotation.reversed();
+        assert_eq!(reversed.angles(), rotation_ii.angles());
+        assert!(reversed.same_rotated_shape(&rotation_ii));
+        assert!(rotation.same_shape(&reversed));
+    }
+}
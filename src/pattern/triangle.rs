@@ -1,36 +1,29 @@
-use tiny_skia::{FillRule, Paint, Path, PathBuilder, Pixmap, Transform};
-
-use crate::{options::Point, pattern_utils::HexCoord};
+use crate::{options::Point, pattern_utils::HexCoord, render::RenderTarget};
 
 pub fn draw_triangle(
     triangle: Point,
-    pixmap: &mut Pixmap,
+    target: &mut dyn RenderTarget,
     location: HexCoord,
     next: HexCoord,
     scale: f32,
 ) {
-    let mut paint = Paint::default();
-
     match triangle {
         Point::None => (),
         Point::Single(marker) => {
             let path = generate_triangle_path(location, next, marker.radius * scale);
-            paint.set_color(marker.color.into());
-            pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
+            target.fill_polygon(&path, marker.color);
         }
         Point::Double { inner, outer } => {
             let path = generate_triangle_path(location, next, outer.radius * scale);
-            paint.set_color(outer.color.into());
-            pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
+            target.fill_polygon(&path, outer.color);
 
             let path = generate_triangle_path(location, next, inner.radius * scale);
-            paint.set_color(inner.color.into());
-            pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
+            target.fill_polygon(&path, inner.color);
         }
     }
 }
 
-fn generate_triangle_path(location: HexCoord, next: HexCoord, radius: f32) -> Path {
+fn generate_triangle_path(location: HexCoord, next: HexCoord, radius: f32) -> [HexCoord; 3] {
     let dir = next - location;
     let magnitude = (dir.0 * dir.0 + dir.1 * dir.1).sqrt();
 
@@ -39,14 +32,7 @@ fn generate_triangle_path(location: HexCoord, next: HexCoord, radius: f32) -> Pa
     //let point3 = location - dir / magnitude * radius / 6.0;
     let point4 = rotate_point(location, point1, (120.0f32).to_radians());
 
-    let mut path = PathBuilder::new();
-    path.move_to(point1.0, point1.1);
-    path.line_to(point2.0, point2.1);
-    //path.line_to(point3.0, point3.1);
-    path.line_to(point4.0, point4.1);
-    path.line_to(point1.0, point1.1);
-
-    path.finish().unwrap()
+    [point1, point2, point4]
 }
 
 pub fn rotate_point(center: HexCoord, point: HexCoord, angle: f32) -> HexCoord {
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use crate::pattern_utils::{Angle, ConnectionPoint};
+
+use super::{Pattern, PatternParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Something about a pattern that isn't allowed ingame, found with [Pattern::validate]
+/// Segments are numbered in the order they're drawn starting at 0
+/// (so the angle at index i is the turn from segment i to segment i + 1)
+pub enum PatternIssue {
+    ///The pattern is a single line with no angles
+    EmptySignature,
+    ///The pattern turns straight back (s) from the segment onto the one after it
+    Backtrack { segment: usize },
+    ///The segment is drawn over the first segment that drew the same line
+    RepeatedEdge { segment: usize, first: usize },
+}
+
+impl Pattern {
+    ///Finds everything about the pattern that isn't allowed ingame (in the order they're drawn)
+    /// Backtracking also draws over a line, but it's only reported as [PatternIssue::Backtrack]
+    pub fn validate(&self) -> Vec<PatternIssue> {
+        let mut issues = Vec::new();
+
+        if self.angles.is_empty() {
+            issues.push(PatternIssue::EmptySignature);
+        }
+
+        let mut drawn = HashMap::new();
+
+        for (segment, points) in self.path.windows(2).enumerate() {
+            let line = ConnectionPoint::new(points[0], points[1]);
+
+            let backtrack = segment > 0 && self.angles[segment - 1] == Angle::Back;
+            if backtrack {
+                issues.push(PatternIssue::Backtrack { segment: segment - 1 });
+            }
+
+            if let Some(first) = drawn.get(&line) {
+                if !backtrack {
+                    issues.push(PatternIssue::RepeatedEdge {
+                        segment,
+                        first: *first,
+                    });
+                }
+            } else {
+                drawn.insert(line, segment);
+            }
+        }
+
+        issues
+    }
+
+    ///Whether the pattern can be drawn ingame (see [Pattern::validate])
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    ///Parses the pattern the same way as [Pattern::try_from]
+    /// but also rejects any pattern that can't be drawn ingame (see [Pattern::validate])
+    pub fn parse_strict(value: &str) -> Result<Pattern, PatternParseError> {
+        let pattern = Pattern::try_from(value)?;
+
+        let issues = pattern.validate();
+        if issues.is_empty() {
+            Ok(pattern)
+        } else {
+            Err(PatternParseError::Illegal {
+                input: value.to_string(),
+                issues,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(pattern: &str) -> Vec<PatternIssue> {
+        Pattern::try_from(pattern).unwrap().validate()
+    }
+
+    #[test]
+    fn valid_patterns() {
+        for pattern in ["NORTH_EAST qaq", "EAST aawdd", "WEST qqqqq", "SOUTH_EAST aqaawaa"] {
+            assert_eq!(issues(pattern), Vec::new(), "{pattern}");
+            assert!(Pattern::try_from(pattern).unwrap().is_valid());
+        }
+    }
+
+    #[test]
+    fn empty_signature() {
+        assert_eq!(issues("EAST"), vec![PatternIssue::EmptySignature]);
+        assert_eq!(issues("HexPattern(WEST)"), vec![PatternIssue::EmptySignature]);
+    }
+
+    #[test]
+    fn backtrack() {
+        //the second angle turns back onto the second segment
+        assert_eq!(issues("EAST qsa"), vec![PatternIssue::Backtrack { segment: 1 }]);
+        assert_eq!(issues("EAST s"), vec![PatternIssue::Backtrack { segment: 0 }]);
+    }
+
+    #[test]
+    fn repeated_edge() {
+        //goes all the way around the hexagon and back over the first line
+        assert_eq!(
+            issues("EAST qqqqqq"),
+            vec![PatternIssue::RepeatedEdge { segment: 6, first: 0 }]
+        );
+        //loops around a triangle and then draws the first line the other way round
+        assert_eq!(
+            issues("EAST qaad"),
+            vec![PatternIssue::RepeatedEdge { segment: 4, first: 0 }]
+        );
+    }
+
+    #[test]
+    fn parse_strict() {
+        assert_eq!(
+            Pattern::parse_strict("EAST aawdd").unwrap(),
+            Pattern::try_from("EAST aawdd").unwrap()
+        );
+
+        match Pattern::parse_strict("HexPattern(EAST qsa)") {
+            Err(PatternParseError::Illegal { input, issues }) => {
+                assert_eq!(input, "HexPattern(EAST qsa)");
+                assert_eq!(issues, vec![PatternIssue::Backtrack { segment: 1 }]);
+            }
+            other => panic!("{other:?}"),
+        }
+        assert!(matches!(
+            Pattern::parse_strict("EAST"),
+            Err(PatternParseError::Illegal { .. })
+        ));
+
+        //patterns that don't parse at all keep their usual errors
+        assert!(matches!(
+            Pattern::parse_strict("EAST qzq"),
+            Err(PatternParseError::InvalidAngle { angle: 'z', .. })
+        ));
+    }
+}
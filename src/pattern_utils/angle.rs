@@ -8,9 +8,36 @@ pub enum Angle {
     Left = 5,
 }
 
+impl Angle {
+    ///Same angle turning the other way (left instead of right and the other way around)
+    pub fn mirror(self) -> Self {
+        match self {
+            Angle::Forward => Angle::Forward,
+            Angle::Right => Angle::Left,
+            Angle::BackRight => Angle::BackLeft,
+            Angle::Back => Angle::Back,
+            Angle::BackLeft => Angle::BackRight,
+            Angle::Left => Angle::Right,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct AngleParseError(pub char);
 
+impl From<Angle> for char {
+    fn from(value: Angle) -> Self {
+        match value {
+            Angle::Forward => 'w',
+            Angle::Right => 'e',
+            Angle::BackRight => 'd',
+            Angle::Back => 's',
+            Angle::BackLeft => 'a',
+            Angle::Left => 'q',
+        }
+    }
+}
+
 impl TryFrom<char> for Angle {
     type Error = AngleParseError;
 
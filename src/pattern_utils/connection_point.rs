@@ -1,6 +1,6 @@
 use super::Coord;
 
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct ConnectionPoint(Coord, Coord);
 
 impl ConnectionPoint {
@@ -8,4 +8,8 @@ impl ConnectionPoint {
         let (a, b) = a.order_by_x(b);
         Self(a, b)
     }
+
+    pub fn points(&self) -> (Coord, Coord) {
+        (self.0, self.1)
+    }
 }
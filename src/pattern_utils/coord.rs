@@ -2,6 +2,9 @@ use super::Direction;
 use std::ops::Add;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///Point on the hex lattice that patterns are drawn on (x, y)
+/// x goes EAST and y goes SOUTH_EAST, so (1, -1) is NORTH_EAST of the origin
+/// Converts into a [HexCoord](super::HexCoord) for its location in grid units
 pub struct Coord(pub i32, pub i32);
 
 impl Add<Direction> for Coord {
@@ -1,5 +1,5 @@
 use super::Angle;
-use std::ops::Add;
+use std::ops::{Add, Sub};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
@@ -19,6 +19,22 @@ impl Add<Angle> for Direction {
     }
 }
 
+impl Sub for Direction {
+    type Output = Angle;
+
+    ///Angle to turn from the second direction to get to the first
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self as u8 + 6 - rhs as u8) % 6 {
+            0 => Angle::Forward,
+            1 => Angle::Right,
+            2 => Angle::BackRight,
+            3 => Angle::Back,
+            4 => Angle::BackLeft,
+            _ => Angle::Left,
+        }
+    }
+}
+
 impl TryFrom<(i32, i32)> for Direction {
     type Error = ();
 
@@ -39,7 +55,7 @@ impl TryFrom<(i32, i32)> for Direction {
 }
 
 impl Direction {
-    #[allow(dead_code)]
+    ///Direction pointing the opposite way
     pub fn get_reverse(self) -> Self {
         match self {
             Self::East => Self::West,
@@ -74,6 +90,19 @@ impl TryFrom<u8> for Direction {
     }
 }
 
+impl From<Direction> for &'static str {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::NorthEast => "NORTH_EAST",
+            Direction::East => "EAST",
+            Direction::SouthEast => "SOUTH_EAST",
+            Direction::SouthWest => "SOUTH_WEST",
+            Direction::West => "WEST",
+            Direction::NorthWest => "NORTH_WEST",
+        }
+    }
+}
+
 impl TryFrom<&str> for Direction {
     type Error = DirectionParseError;
 
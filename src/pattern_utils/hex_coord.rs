@@ -3,6 +3,9 @@ use std::ops::{Add, Div, Mul, Sub};
 use super::Coord;
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Point in floating point space (x, y)
+/// Used for both positions on the hex grid and pixel locations when drawing
 pub struct HexCoord(pub f32, pub f32);
 
 const Y_FACTOR: f32 = 0.866_025_4;
@@ -61,6 +64,25 @@ impl HexCoord {
         self / self.magnitude()
     }
 
+    ///Point on the lattice closest to this location (in grid units)
+    pub fn nearest_coord(self) -> Coord {
+        //rounds in cube coordinates (x + y + z = 0) so the closest point is picked near the edges
+        let y = self.1 / Y_FACTOR;
+        let x = self.0 - 0.5 * y;
+        let z = -x - y;
+
+        let (mut rx, mut ry, rz) = (x.round(), y.round(), z.round());
+        let (dx, dy, dz) = ((rx - x).abs(), (ry - y).abs(), (rz - z).abs());
+
+        if dx > dy && dx > dz {
+            rx = -ry - rz;
+        } else if dy > dz {
+            ry = -rx - rz;
+        }
+
+        Coord(rx as i32, ry as i32)
+    }
+
     pub fn min_components(self, rhs: Self) -> Self {
         Self(self.0.min(rhs.0), self.1.min(rhs.1))
     }
@@ -1,95 +1,92 @@
-use tiny_skia::{Color, Paint, Path, PathBuilder, Pixmap, Shader, Stroke, Transform};
+use crate::{
+    options::Color,
+    render::{LinePaint, LineStyle, RenderTarget},
+};
 
 use super::HexCoord;
 
-pub struct LineDrawer<'a> {
+pub struct LineDrawer {
     prev_point: HexCoord,
-    paths: Vec<(Path, Stroke, Paint<'a>)>,
-    priority_paths: Vec<(Path, Stroke, Paint<'a>)>,
-    path: PathBuilder,
-    stroke: Stroke,
-    paint: Paint<'a>,
+    paths: Vec<(Vec<HexCoord>, LineStyle, LinePaint)>,
+    priority_paths: Vec<(Vec<HexCoord>, LineStyle, LinePaint)>,
+    path: Vec<HexCoord>,
+    stroke: LineStyle,
+    paint: LinePaint,
 }
-impl<'a> LineDrawer<'a> {
-    pub fn new(start_point: HexCoord, stroke: Stroke, paint: Paint<'a>) -> Self {
-        let mut path = PathBuilder::new();
-        path.move_to(start_point.0, start_point.1);
+impl LineDrawer {
+    pub fn new(start_point: HexCoord, stroke: LineStyle, paint: LinePaint) -> Self {
         Self {
             prev_point: start_point,
             paths: Vec::new(),
             priority_paths: Vec::new(),
-            path,
+            path: vec![start_point],
             stroke,
             paint,
         }
     }
     pub fn line_to(&mut self, point: HexCoord) {
         self.prev_point = point;
-        self.path.line_to(point.0, point.1);
+        self.path.push(point);
     }
 
-    fn new_path(&mut self, start_point: HexCoord, mut stroke: Stroke, mut paint: Paint<'a>) {
-        let mut tmp_path = PathBuilder::new();
-        tmp_path.move_to(start_point.0, start_point.1);
+    fn new_path(&mut self, start_point: HexCoord, mut stroke: LineStyle, mut paint: LinePaint) {
+        let mut tmp_path = vec![start_point];
 
         self.prev_point = start_point;
 
         std::mem::swap(&mut tmp_path, &mut self.path);
         std::mem::swap(&mut paint, &mut self.paint);
         std::mem::swap(&mut stroke, &mut self.stroke);
-        if let Some(path) = tmp_path.finish() {
-            self.paths.push((path, stroke, paint));
+        if tmp_path.len() > 1 {
+            self.paths.push((tmp_path, stroke, paint));
         }
     }
 
     pub fn move_to(&mut self, point: HexCoord) {
-        self.new_path(point, self.stroke.clone(), self.paint.clone());
+        self.new_path(point, self.stroke.clone(), self.paint);
     }
 
     pub fn set_color(&mut self, color: Color) {
-        self.new_path(self.prev_point, self.stroke.clone(), self.paint.clone());
-        self.paint.set_color(color);
+        self.new_path(self.prev_point, self.stroke.clone(), self.paint);
+        self.paint = LinePaint::Solid(color);
     }
 
-    pub fn set_shader(&mut self, shader: Shader<'a>) {
-        let mut paint = self.paint.clone();
-        paint.shader = shader;
+    pub fn set_paint(&mut self, paint: LinePaint) {
         self.new_path(self.prev_point, self.stroke.clone(), paint);
     }
     pub fn set_width(&mut self, width: f32) {
-        self.new_path(self.prev_point, self.stroke.clone(), self.paint.clone());
+        self.new_path(self.prev_point, self.stroke.clone(), self.paint);
         self.stroke.width = width;
     }
 
-    pub fn set_stroke(&mut self, stroke: Stroke) {
-        self.new_path(self.prev_point, stroke, self.paint.clone());
+    pub fn set_stroke(&mut self, stroke: LineStyle) {
+        self.new_path(self.prev_point, stroke, self.paint);
     }
 
     pub fn priority_finish(&mut self) {
-        let mut tmp = PathBuilder::new();
-        tmp.move_to(self.prev_point.0, self.prev_point.1);
+        let mut tmp = vec![self.prev_point];
 
         std::mem::swap(&mut tmp, &mut self.path);
 
-        if let Some(path) = tmp.finish() {
+        if tmp.len() > 1 {
             self.priority_paths
-                .push((path, self.stroke.clone(), self.paint.clone()));
+                .push((tmp, self.stroke.clone(), self.paint));
         }
     }
 
-    pub fn draw(&mut self, pixmap: &mut Pixmap) {
-        self.new_path(self.prev_point, self.stroke.clone(), self.paint.clone());
+    pub fn draw(&mut self, target: &mut dyn RenderTarget) {
+        self.new_path(self.prev_point, self.stroke.clone(), self.paint);
         for (path, stroke, paint) in self.paths.iter().rev() {
-            pixmap.stroke_path(path, paint, stroke, Transform::identity(), None)
+            target.stroke_path(path, stroke, paint)
         }
     }
-    pub fn draw_priority(self, pixmap: &mut Pixmap) {
+    pub fn draw_priority(self, target: &mut dyn RenderTarget) {
         for (path, stroke, paint) in self.priority_paths {
-            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None)
+            target.stroke_path(&path, &stroke, &paint)
         }
     }
-    pub fn draw_all(mut self, pixmap: &mut Pixmap) {
-        self.draw(pixmap);
-        self.draw_priority(pixmap);
+    pub fn draw_all(mut self, target: &mut dyn RenderTarget) {
+        self.draw(target);
+        self.draw_priority(target);
     }
 }
@@ -3,7 +3,7 @@ mod angle;
 pub use angle::{Angle, AngleParseError};
 
 mod coord;
-pub(crate) use coord::Coord;
+pub use coord::Coord;
 
 mod direction;
 pub use direction::{Direction, DirectionParseError};
@@ -12,7 +12,7 @@ mod dynamic_list;
 pub(crate) use dynamic_list::DynamicList;
 
 mod hex_coord;
-pub(crate) use hex_coord::HexCoord;
+pub use hex_coord::HexCoord;
 
 mod connection_point;
 pub(crate) use connection_point::ConnectionPoint;
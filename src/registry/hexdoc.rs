@@ -0,0 +1,163 @@
+use serde_json::{Map, Value};
+
+use super::{
+    parse::{parse_direction, parse_signature},
+    PatternEntry, PatternRegistry, RegistryParseError,
+};
+
+const NAME_KEYS: [&str; 2] = ["name", "display_name"];
+const ID_KEYS: [&str; 3] = ["id", "op_id", "resource_location"];
+const SIGNATURE_KEYS: [&str; 3] = ["signature", "sig", "angles"];
+const DIRECTION_KEYS: [&str; 4] = ["startdir", "start_dir", "start_direction", "direction"];
+const PER_WORLD_KEYS: [&str; 3] = ["is_per_world", "per_world", "isPerWorld"];
+
+impl PatternRegistry {
+    ///Loads the patterns exported by hexdoc (or addon docs)
+    /// Accepts a list of patterns or a map of id to pattern (optionally under a "patterns" key)
+    /// where each pattern has a name, signature, start direction and is_per_world
+    /// eg. `[{"id": "hexcasting:get_caster", "name": "Mind's Reflection", "startdir": "NORTH_EAST", "signature": "qaq", "is_per_world": false}]`
+    pub fn from_hexdoc_json(json: &str) -> Result<Self, RegistryParseError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|err| RegistryParseError::InvalidJson(err.to_string()))?;
+
+        let value = match value {
+            Value::Object(mut map) if map.contains_key("patterns") => map.remove("patterns").unwrap(),
+            value => value,
+        };
+
+        let entries = match value {
+            Value::Array(list) => list
+                .iter()
+                .map(|value| (None, value))
+                .map(parse_json_entry)
+                .collect::<Result<Vec<_>, _>>()?,
+            Value::Object(map) => map
+                .iter()
+                .map(|(id, value)| (Some(id), value))
+                .map(parse_json_entry)
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(RegistryParseError::InvalidLayout),
+        };
+
+        let mut registry = Self::new();
+        for entry in entries {
+            registry.insert(entry);
+        }
+        Ok(registry)
+    }
+}
+
+fn parse_json_entry((id, value): (Option<&String>, &Value)) -> Result<PatternEntry, RegistryParseError> {
+    let object = value.as_object().ok_or(RegistryParseError::InvalidLayout)?;
+
+    let id = id.cloned().or_else(|| get_str(object, &ID_KEYS).map(str::to_string));
+    let name = get_str(object, &NAME_KEYS)
+        .map(str::to_string)
+        .or_else(|| id.clone())
+        .ok_or_else(|| RegistryParseError::MissingField {
+            entry: value.to_string(),
+            field: "name",
+        })?;
+
+    let direction = get_str(object, &DIRECTION_KEYS).ok_or_else(|| RegistryParseError::MissingField {
+        entry: name.clone(),
+        field: "startdir",
+    })?;
+    let signature = get_str(object, &SIGNATURE_KEYS).ok_or_else(|| RegistryParseError::MissingField {
+        entry: name.clone(),
+        field: "signature",
+    })?;
+
+    let per_world = PER_WORLD_KEYS
+        .iter()
+        .find_map(|key| object.get(*key))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    Ok(PatternEntry {
+        start_direction: parse_direction(&name, direction)?,
+        angles: parse_signature(&name, signature)?,
+        name,
+        id,
+        per_world,
+    })
+}
+
+fn get_str<'a>(object: &'a Map<String, Value>, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|key| object.get(*key))
+        .and_then(Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pattern_utils::Direction, Pattern};
+
+    use super::*;
+
+    ///Trimmed down export in the layout hexdoc uses (a map of id to pattern under "patterns")
+    const FIXTURE: &str = r#"{
+        "patterns": {
+            "hexcasting:get_caster": {
+                "name": "Mind's Reflection",
+                "startdir": "NORTH_EAST",
+                "signature": "qaq",
+                "is_per_world": false
+            },
+            "hexcasting:swap": {
+                "name": "Jester's Gambit",
+                "startdir": "EAST",
+                "signature": "aawdd",
+                "is_per_world": false
+            },
+            "hexcasting:create_lava": {
+                "name": "Create Lava",
+                "startdir": "EAST",
+                "signature": "eaqawqadaqd",
+                "is_per_world": true
+            }
+        }
+    }"#;
+
+    #[test]
+    fn from_hexdoc_json() {
+        let registry = PatternRegistry::from_hexdoc_json(FIXTURE).unwrap();
+        assert_eq!(registry.len(), 3);
+
+        let entry = registry.get_by_name("mind's reflection").unwrap();
+        assert_eq!(entry.id.as_deref(), Some("hexcasting:get_caster"));
+        assert_eq!(entry.start_direction, Direction::NorthEast);
+        assert!(!entry.per_world);
+        assert!(registry.get_by_name("Create Lava").unwrap().per_world);
+
+        let swap = Pattern::try_from("EAST aawdd").unwrap();
+        assert_eq!(registry.get_name(&swap), Some("Jester's Gambit"));
+    }
+
+    #[test]
+    fn from_hexdoc_json_list() {
+        let json = r#"[{"op_id": "hexcasting:get_caster", "startdir": "NORTH_EAST", "sig": "qaq"}]"#;
+        let registry = PatternRegistry::from_hexdoc_json(json).unwrap();
+
+        //without a name it falls back to the id
+        let entry = &registry.entries()[0];
+        assert_eq!(entry.name, "hexcasting:get_caster");
+        assert_eq!(entry.id.as_deref(), Some("hexcasting:get_caster"));
+    }
+
+    #[test]
+    fn from_hexdoc_json_errors() {
+        assert!(matches!(
+            PatternRegistry::from_hexdoc_json("[{"),
+            Err(RegistryParseError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            PatternRegistry::from_hexdoc_json("\"qaq\""),
+            Err(RegistryParseError::InvalidLayout)
+        ));
+        assert!(matches!(
+            PatternRegistry::from_hexdoc_json(r#"[{"name": "Mind's Reflection", "signature": "qaq"}]"#),
+            Err(RegistryParseError::MissingField { field: "startdir", .. })
+        ));
+    }
+}
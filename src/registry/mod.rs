@@ -0,0 +1,206 @@
+//! Registry for looking up the names of patterns (eg. "Mind's Reflection" or "Hermes' Gambit")
+//!
+//! Normal patterns are found by their angle signature while per world patterns (great spells)
+//! are found by their shape (see [Pattern::same_shape]).
+//!
+//! A [PatternRegistry] can be loaded from the pattern json exported by hexdoc (`PatternRegistry::from_hexdoc_json`,
+//! which needs the `hexdoc` feature)
+//! or from a simple tab separated list ([PatternRegistry::from_tsv]).
+
+#[cfg(feature = "hexdoc")]
+mod hexdoc;
+mod parse;
+pub use parse::RegistryParseError;
+
+use std::collections::HashMap;
+
+use crate::{
+    pattern::ShapeKey,
+    pattern_utils::{Angle, Direction},
+    Pattern,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+///Named pattern within a [PatternRegistry]
+pub struct PatternEntry {
+    ///Human readable name (eg. Mind's Reflection)
+    pub name: String,
+    ///Resource id if there is one (eg. hexcasting:get_caster)
+    pub id: Option<String>,
+    ///Start direction of the pattern
+    pub start_direction: Direction,
+    ///Angle signature of the pattern
+    pub angles: Vec<Angle>,
+    ///Whether it's a per world pattern (great spell) that is matched by its shape instead of its signature
+    pub per_world: bool,
+}
+
+impl PatternEntry {
+    ///Creates the [Pattern] of the entry
+    pub fn to_pattern(&self) -> Pattern {
+        Pattern::new(self.start_direction, self.angles.clone())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+///Maps patterns to their names
+pub struct PatternRegistry {
+    entries: Vec<PatternEntry>,
+    signatures: HashMap<Vec<Angle>, usize>,
+    shapes: HashMap<ShapeKey, usize>,
+}
+
+impl PatternRegistry {
+    ///Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Adds an entry to the registry, replacing any entry with the same signature (or shape for per world patterns)
+    pub fn insert(&mut self, entry: PatternEntry) {
+        let index = self.entries.len();
+
+        let existing = if entry.per_world {
+            *self
+                .shapes
+                .entry(entry.to_pattern().shape_key(false))
+                .or_insert(index)
+        } else {
+            *self.signatures.entry(entry.angles.clone()).or_insert(index)
+        };
+
+        if existing == index {
+            self.entries.push(entry);
+        } else {
+            self.entries[existing] = entry;
+        }
+    }
+
+    ///Finds the entry for the given pattern
+    /// Normal patterns are matched by their signature, per world patterns by their shape
+    pub fn lookup(&self, pattern: &Pattern) -> Option<&PatternEntry> {
+        self.signatures
+            .get(pattern.angles())
+            .or_else(|| self.shapes.get(&pattern.shape_key(false)))
+            .map(|index| &self.entries[*index])
+    }
+
+    ///Finds the name of the given pattern
+    pub fn get_name(&self, pattern: &Pattern) -> Option<&str> {
+        self.lookup(pattern).map(|entry| &entry.name[..])
+    }
+
+    ///Finds the entry with the given name (ignoring case)
+    pub fn get_by_name(&self, name: &str) -> Option<&PatternEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+
+    ///All entries in the registry (in the order they were added)
+    pub fn entries(&self) -> &[PatternEntry] {
+        &self.entries
+    }
+
+    ///Number of entries in the registry
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    ///Whether the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+///Anything that can give names to patterns (such as for captions)
+/// Implemented for [PatternRegistry] and for closures of `Fn(&Pattern) -> Option<String>`
+pub trait NameSource {
+    ///Name of the pattern (or None if it doesn't have one)
+    fn get_pattern_name(&self, pattern: &Pattern) -> Option<String>;
+}
+
+impl<F: Fn(&Pattern) -> Option<String>> NameSource for F {
+    fn get_pattern_name(&self, pattern: &Pattern) -> Option<String> {
+        self(pattern)
+    }
+}
+
+impl NameSource for PatternRegistry {
+    fn get_pattern_name(&self, pattern: &Pattern) -> Option<String> {
+        self.get_name(pattern).map(str::to_string)
+    }
+}
+
+impl Pattern {
+    ///Finds the entry for the pattern in the given registry
+    pub fn lookup<'a>(&self, registry: &'a PatternRegistry) -> Option<&'a PatternEntry> {
+        registry.lookup(self)
+    }
+
+    ///Finds the name of the pattern in the given registry
+    pub fn name<'a>(&self, registry: &'a PatternRegistry) -> Option<&'a str> {
+        registry.get_name(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, pattern: &str, per_world: bool) -> PatternEntry {
+        let pattern = Pattern::try_from(pattern).unwrap();
+
+        PatternEntry {
+            name: name.to_string(),
+            id: None,
+            start_direction: pattern.start_direction(),
+            angles: pattern.angles().to_vec(),
+            per_world,
+        }
+    }
+
+    fn registry() -> PatternRegistry {
+        let mut registry = PatternRegistry::new();
+        registry.insert(entry("Mind's Reflection", "NORTH_EAST qaq", false));
+        registry.insert(entry("Create Lava", "EAST eaqawqadaqd", true));
+        registry
+    }
+
+    #[test]
+    fn lookup_by_signature() {
+        let registry = registry();
+
+        //normal patterns can be drawn in any direction
+        for pattern in ["NORTH_EAST qaq", "WEST qaq"] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+            assert_eq!(registry.get_name(&pattern), Some("Mind's Reflection"));
+        }
+        assert_eq!(registry.get_name(&Pattern::try_from("NORTH_EAST qaqa").unwrap()), None);
+    }
+
+    #[test]
+    fn lookup_per_world_by_shape() {
+        let registry = registry();
+        let lava = Pattern::try_from("EAST eaqawqadaqd").unwrap();
+
+        //great spells match when the same lines are drawn in any order
+        assert_eq!(registry.get_name(&lava), Some("Create Lava"));
+        assert_eq!(registry.get_name(&lava.reversed()), Some("Create Lava"));
+
+        //but not when they're rotated or mirrored
+        assert_eq!(registry.get_name(&lava.rotated(1)), None);
+        assert_eq!(registry.get_name(&lava.mirrored()), None);
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut registry = registry();
+        registry.insert(entry("Create Lava (again)", "EAST eaqawqadaqd", true));
+        registry.insert(entry("Mind's Reflection (again)", "EAST qaq", false));
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.entries()[0].name, "Mind's Reflection (again)");
+        assert_eq!(registry.get_by_name("create lava (AGAIN)").unwrap().name, "Create Lava (again)");
+    }
+}
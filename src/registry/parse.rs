@@ -0,0 +1,154 @@
+use crate::pattern_utils::{Angle, Direction};
+
+use super::{PatternEntry, PatternRegistry};
+
+#[derive(Debug, Clone)]
+pub enum RegistryParseError {
+    ///The input isn't valid json (only from `from_hexdoc_json`)
+    InvalidJson(String),
+    ///The json isn't a list/map of patterns
+    InvalidLayout,
+    ///An entry is missing a required field
+    MissingField { entry: String, field: &'static str },
+    ///An entry has an invalid start direction
+    InvalidDirection { entry: String, direction: String },
+    ///An entry's signature contains a character that isn't an angle
+    InvalidAngle { entry: String, angle: char },
+    ///A line of the tsv doesn't have enough columns (line numbers start at 1)
+    InvalidLine { line: usize, content: String },
+}
+
+impl PatternRegistry {
+    ///Loads patterns from tab separated lines of:
+    /// name, start direction, signature and (optionally) whether it's per world (true/false)
+    /// Empty lines, lines starting with # and header lines (starting with "name" instead of a pattern) are skipped
+    /// eg. `Mind's Reflection\tNORTH_EAST\tqaq` (where \t is a tab)
+    pub fn from_tsv(tsv: &str) -> Result<Self, RegistryParseError> {
+        let mut registry = Self::new();
+
+        for (index, line) in tsv.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+            if is_header(&columns) {
+                continue;
+            }
+
+            if columns.len() < 3 {
+                return Err(RegistryParseError::InvalidLine {
+                    line: index + 1,
+                    content: line.to_string(),
+                });
+            }
+
+            let name = columns[0];
+            let per_world = columns
+                .get(3)
+                .map(|column| matches!(&column.to_lowercase()[..], "true" | "1" | "yes"))
+                .unwrap_or(false);
+
+            registry.insert(PatternEntry {
+                name: name.to_string(),
+                id: None,
+                start_direction: parse_direction(name, columns[1])?,
+                angles: parse_signature(name, columns[2])?,
+                per_world,
+            });
+        }
+
+        Ok(registry)
+    }
+}
+
+///Whether the columns are a header (eg. name, direction, signature) rather than a pattern
+/// The start direction is checked too, so a pattern that's actually called "name" still loads
+fn is_header(columns: &[&str]) -> bool {
+    let direction = columns.get(1).and_then(|direction| Direction::try_from(*direction).ok());
+
+    columns[0].eq_ignore_ascii_case("name") && direction.is_none()
+}
+
+pub(super) fn parse_direction(entry: &str, direction: &str) -> Result<Direction, RegistryParseError> {
+    Direction::try_from(direction).map_err(|_| RegistryParseError::InvalidDirection {
+        entry: entry.to_string(),
+        direction: direction.to_string(),
+    })
+}
+
+pub(super) fn parse_signature(entry: &str, signature: &str) -> Result<Vec<Angle>, RegistryParseError> {
+    signature
+        .chars()
+        .map(|char| {
+            Angle::try_from(char).map_err(|err| RegistryParseError::InvalidAngle {
+                entry: entry.to_string(),
+                angle: err.0,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Pattern;
+
+    use super::*;
+
+    const TSV: &str = "# patterns from the base mod
+Name\tDirection\tSignature\tPer World
+
+Mind's Reflection\tNORTH_EAST\tqaq
+Jester's Gambit\tEAST\taawdd\tfalse
+  # indented comments are skipped too
+Create Lava\tEAST\teaqawqadaqd\ttrue
+";
+
+    #[test]
+    fn from_tsv() {
+        let registry = PatternRegistry::from_tsv(TSV).unwrap();
+
+        assert_eq!(registry.len(), 3);
+        assert_eq!(
+            registry.entries()[0],
+            PatternEntry {
+                name: "Mind's Reflection".to_string(),
+                id: None,
+                start_direction: Direction::NorthEast,
+                angles: Pattern::try_from("NORTH_EAST qaq").unwrap().angles().to_vec(),
+                per_world: false,
+            }
+        );
+        assert!(!registry.entries()[1].per_world);
+        assert!(registry.entries()[2].per_world);
+    }
+
+    #[test]
+    fn header_is_found_by_content() {
+        //the header doesn't have to be on the first line
+        let registry = PatternRegistry::from_tsv("\n\nname\tstartdir\tsignature\nMind's Reflection\tNORTH_EAST\tqaq").unwrap();
+        assert_eq!(registry.len(), 1);
+
+        //and a pattern that's called name isn't a header
+        let registry = PatternRegistry::from_tsv("Name\tEAST\tqaq\nName Two\tEAST\teee").unwrap();
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.entries()[0].name, "Name");
+    }
+
+    #[test]
+    fn from_tsv_errors() {
+        assert!(matches!(
+            PatternRegistry::from_tsv("# comment\nMind's Reflection\tNORTH_EAST"),
+            Err(RegistryParseError::InvalidLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            PatternRegistry::from_tsv("Mind's Reflection\tUP\tqaq"),
+            Err(RegistryParseError::InvalidDirection { direction, .. }) if direction == "UP"
+        ));
+        assert!(matches!(
+            PatternRegistry::from_tsv("Mind's Reflection\tNORTH_EAST\tqxq"),
+            Err(RegistryParseError::InvalidAngle { angle: 'x', .. })
+        ));
+    }
+}
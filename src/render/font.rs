@@ -0,0 +1,77 @@
+use lazy_static::lazy_static;
+use rusttype::{point, Font, PositionedGlyph, Scale};
+
+lazy_static! {
+    static ref LATO: Font<'static> = {
+        let font_file = include_bytes!("../Lato-Regular.ttf");
+        Font::try_from_bytes(font_file).expect("error constructing font!")
+    };
+    static ref SEVEN_SEGMENT: Font<'static> = {
+        let font_file = include_bytes!("../7seg.otf");
+        Font::try_from_bytes(font_file).expect("error constructing font!")
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Fonts bundled with the renderer
+pub enum TextFont {
+    ///Lato, used for most text
+    #[default]
+    Lato,
+    ///Seven segment display font (only has digits and a few symbols like - and .)
+    SevenSegment,
+}
+
+impl TextFont {
+    fn font(self) -> &'static Font<'static> {
+        match self {
+            TextFont::Lato => &LATO,
+            TextFont::SevenSegment => &SEVEN_SEGMENT,
+        }
+    }
+
+    ///Height of the text in relation to the font size
+    fn height_scale(self) -> f32 {
+        match self {
+            TextFont::Lato => 22.0 / 30.0,
+            TextFont::SevenSegment => 0.84,
+        }
+    }
+
+    ///Distance from the top of the text to the baseline in relation to the font size
+    fn baseline(self) -> f32 {
+        match self {
+            TextFont::Lato => 19.0 / 30.0,
+            //the digits are centered on the baseline
+            TextFont::SevenSegment => 0.42,
+        }
+    }
+}
+
+///Lays out the text with the given bundled font
+/// returns the glyphs (positioned relative to the top left of the text) along with the width and height of the text
+pub fn layout_text(str: &str, size: f32, font: TextFont) -> (Vec<PositionedGlyph<'static>>, f32, f32) {
+    let scale = Scale::uniform(size);
+
+    let width = text_width(str, size, font);
+    let height = font.height_scale() * size;
+
+    let offset = point(0.0, font.baseline() * size);
+
+    (font.font().layout(str, scale, offset).collect(), width, height)
+}
+
+///Largest font size where the text still fits within the given box
+pub fn fit_text(str: &str, width: f32, height: f32, font: TextFont) -> f32 {
+    (height / font.height_scale()).min(width / text_width(str, 1.0, font))
+}
+
+///Width of the text (in pixels) when drawn with the given font size
+pub fn text_width(str: &str, size: f32, font: TextFont) -> f32 {
+    font.font()
+        .layout(str, Scale::uniform(size), point(0.0, 0.0))
+        .last()
+        .map(|glyph| glyph.position().x + glyph.unpositioned().h_metrics().advance_width)
+        .unwrap_or(0.0)
+}
@@ -0,0 +1,106 @@
+//! Drawing primitives that every pattern renderer draws through.
+//!
+//! The pattern drawing code only ever talks to a [RenderTarget], which lets the same
+//! rendering logic output to a [tiny_skia::Pixmap] (png) or to an [SvgCanvas] (svg).
+//!
+//! To draw with your own backend (eg. a web canvas or a recorder for tests),
+//! implement [RenderTarget] and pass it to [GridDraw::draw_grid_on](crate::grids::GridDraw::draw_grid_on).
+
+mod font;
+pub(crate) use font::{fit_text, layout_text, text_width};
+pub use font::TextFont;
+
+mod pixmap;
+
+mod svg;
+pub use svg::SvgCanvas;
+
+use crate::{options::Color, pattern_utils::HexCoord};
+
+///Set of primitives needed to draw patterns
+/// All coordinates are in pixels with the origin in the top left corner
+pub trait RenderTarget {
+    ///Strokes a line going through all of the given points
+    fn stroke_path(&mut self, points: &[HexCoord], stroke: &LineStyle, paint: &LinePaint);
+
+    ///Fills a circle with the given color
+    fn fill_circle(&mut self, center: HexCoord, radius: f32, color: Color);
+
+    ///Fills a closed polygon made up of the given points
+    fn fill_polygon(&mut self, points: &[HexCoord], color: Color);
+
+    ///Draws text with one of the bundled fonts centered on the given point
+    /// * size - height of the font (in pixels)
+    fn draw_text(&mut self, text: &str, center: HexCoord, size: f32, font: TextFont, color: Color);
+
+    ///Draws everything drawn by the closure blurred (used for glows)
+    /// Targets that can't blur can leave this out, in which case nothing is drawn
+    /// * radius - How far (in pixels) the blur spreads things out
+    /// * strength - Amount to multiply the alpha of the blurred result by
+    fn draw_blurred(&mut self, radius: f32, strength: f32, draw: &mut dyn FnMut(&mut dyn RenderTarget)) {
+        let _ = (radius, strength, draw);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Shape at the ends of a line
+pub enum LineCap {
+    ///Ends exactly at the end points
+    Butt,
+    ///Half circle past each end point
+    Round,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Shape at the corners of a line
+pub enum LineJoin {
+    ///Sharp corner
+    Miter,
+    ///Rounded corner
+    Round,
+    ///Corner cut off flat
+    Bevel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+///How to stroke a line
+pub struct LineStyle {
+    ///Width of the line (in pixels)
+    pub width: f32,
+    ///Shape of the ends of the line
+    pub cap: LineCap,
+    ///Shape of the corners of the line
+    pub join: LineJoin,
+    ///Alternating lengths of dashes and gaps (None for a solid line)
+    pub dash: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+///What to color a line with
+pub enum LinePaint {
+    ///Single color
+    Solid(Color),
+    ///Linear gradient going from start_color at start to end_color at end
+    Gradient {
+        ///Point where the gradient is start_color
+        start: HexCoord,
+        ///Point where the gradient is end_color
+        end: HexCoord,
+        ///Color at the start point (and before it)
+        start_color: Color,
+        ///Color at the end point (and after it)
+        end_color: Color,
+    },
+}
+
+impl LineStyle {
+    ///Solid line with rounded caps and joins
+    pub fn round(width: f32) -> Self {
+        Self {
+            width,
+            cap: LineCap::Round,
+            join: LineJoin::Round,
+            dash: None,
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use tiny_skia::{
+    FillRule, GradientStop, LinearGradient, Paint, PathBuilder, Pixmap, PixmapPaint, Shader,
+    SpreadMode, Stroke, StrokeDash, Transform,
+};
+
+use crate::{options::Color, pattern_utils::HexCoord};
+
+use super::{layout_text, LineCap, LineJoin, LinePaint, LineStyle, RenderTarget, TextFont};
+
+impl RenderTarget for Pixmap {
+    fn stroke_path(&mut self, points: &[HexCoord], stroke: &LineStyle, paint: &LinePaint) {
+        let mut path = PathBuilder::new();
+        for (i, point) in points.iter().enumerate() {
+            if i == 0 {
+                path.move_to(point.0, point.1);
+            } else {
+                path.line_to(point.0, point.1);
+            }
+        }
+        let Some(path) = path.finish() else {
+            return;
+        };
+
+        self.stroke_path(
+            &path,
+            &paint.into(),
+            &stroke.into(),
+            Transform::identity(),
+            None,
+        );
+    }
+
+    fn fill_circle(&mut self, center: HexCoord, radius: f32, color: Color) {
+        let Some(path) = PathBuilder::from_circle(center.0, center.1, radius) else {
+            return;
+        };
+        let mut paint = Paint::default();
+        paint.set_color(color.into());
+        self.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
+    }
+
+    fn fill_polygon(&mut self, points: &[HexCoord], color: Color) {
+        let mut path = PathBuilder::new();
+        for (i, point) in points.iter().enumerate() {
+            if i == 0 {
+                path.move_to(point.0, point.1);
+            } else {
+                path.line_to(point.0, point.1);
+            }
+        }
+        path.close();
+        let Some(path) = path.finish() else {
+            return;
+        };
+
+        let mut paint = Paint::default();
+        paint.set_color(color.into());
+        self.fill_path(&path, &paint, FillRule::Winding, Transform::default(), None);
+    }
+
+    fn draw_text(&mut self, text: &str, center: HexCoord, size: f32, font: TextFont, color: Color) {
+        let (glyphs, width, height) = layout_text(text, size, font);
+
+        let Some(mut tmp_map) = Pixmap::new(width as u32, height as u32) else {
+            return;
+        };
+
+        let mut color = tiny_skia::Color::from(color);
+        let pixels = tmp_map.pixels_mut();
+
+        for g in glyphs {
+            if let Some(bb) = g.pixel_bounding_box() {
+                g.draw(|x, y, v| {
+                    let x = x as i32 + bb.min.x;
+                    let y = y as i32 + bb.min.y;
+
+                    if x >= 0 && x < width as i32 && y >= 0 && y < height as i32 {
+                        let x = x as usize;
+                        let y = y as usize;
+                        color.set_alpha(v);
+                        pixels[x + y * width as usize] = color.premultiply().to_color_u8();
+                    }
+                })
+            }
+        }
+        let map_offset = center - HexCoord(width, height) / 2.0;
+
+        self.draw_pixmap(
+            map_offset.0 as i32,
+            map_offset.1 as i32,
+            tmp_map.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+    }
+
+    fn draw_blurred(&mut self, radius: f32, strength: f32, draw: &mut dyn FnMut(&mut dyn RenderTarget)) {
+        let Some(mut layer) = Pixmap::new(self.width(), self.height()) else {
+            return;
+        };
+        draw(&mut layer);
+        blur(&mut layer, radius, strength);
+
+        self.draw_pixmap(
+            0,
+            0,
+            layer.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+    }
+}
+
+///Blurs the pixmap (close to a gaussian blur spreading out by about the radius)
+/// and multiplies its alpha by strength
+fn blur(pixmap: &mut Pixmap, radius: f32, strength: f32) {
+    let (width, height) = (pixmap.width() as usize, pixmap.height() as usize);
+
+    //the pixels are premultiplied so the colors can be blurred the same way as the alpha
+    let mut pixels = pixmap
+        .data()
+        .chunks_exact(4)
+        .map(|pixel| [pixel[0] as f32, pixel[1] as f32, pixel[2] as f32, pixel[3] as f32])
+        .collect::<Vec<[f32; 4]>>();
+
+    let mut line = Vec::new();
+    for box_radius in box_radii(radius / 2.0) {
+        for y in 0..height {
+            box_blur(&mut pixels, y * width, 1, width, box_radius, &mut line);
+        }
+        for x in 0..width {
+            box_blur(&mut pixels, x, width, height, box_radius, &mut line);
+        }
+    }
+
+    for (pixel, blurred) in pixmap.data_mut().chunks_exact_mut(4).zip(pixels) {
+        for (channel, value) in pixel.iter_mut().zip(blurred) {
+            *channel = (value * strength).round().clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
+///Radii of the 3 box blurs that together are about the same as a gaussian blur with the given standard deviation
+fn box_radii(deviation: f32) -> [usize; 3] {
+    if !(deviation.is_finite() && deviation > 0.0) {
+        return [0; 3];
+    }
+    let variance = 12.0 * deviation * deviation;
+
+    //widest odd box that's no wider than the ideal one
+    let ideal = ((variance / 3.0 + 1.0).sqrt().floor() as usize).max(1);
+    let lower = (ideal - 1) / 2 * 2 + 1;
+    let lower_width = lower as f32;
+    let lower_count = ((variance - 3.0 * lower_width * lower_width - 12.0 * lower_width - 9.0)
+        / (-4.0 * lower_width - 4.0))
+        .round()
+        .clamp(0.0, 3.0) as usize;
+
+    //the boxes are either lower or lower + 2 wide
+    let mut radii = [lower / 2 + 1; 3];
+    for radius in radii.iter_mut().take(lower_count) {
+        *radius = lower / 2;
+    }
+    radii
+}
+
+///Box blurs one row or column of pixels (anything outside of the image counts as transparent)
+/// * start - Index of the first pixel
+/// * step - Distance between the indices of each pixel
+/// * line - Buffer to hold a copy of the original pixels
+fn box_blur(pixels: &mut [[f32; 4]], start: usize, step: usize, len: usize, radius: usize, line: &mut Vec<[f32; 4]>) {
+    if radius == 0 {
+        return;
+    }
+    line.clear();
+    line.extend((0..len).map(|i| pixels[start + i * step]));
+
+    let size = (radius * 2 + 1) as f32;
+    let mut sum = [0.0; 4];
+
+    for pixel in line.iter().take(radius) {
+        for (total, value) in sum.iter_mut().zip(pixel) {
+            *total += value;
+        }
+    }
+    for i in 0..len {
+        if let Some(pixel) = line.get(i + radius) {
+            for (total, value) in sum.iter_mut().zip(pixel) {
+                *total += value;
+            }
+        }
+        if i > radius {
+            for (total, value) in sum.iter_mut().zip(&line[i - radius - 1]) {
+                *total -= value;
+            }
+        }
+        pixels[start + i * step] = sum.map(|total| total / size);
+    }
+}
+
+impl From<&LineStyle> for Stroke {
+    fn from(value: &LineStyle) -> Self {
+        Stroke {
+            width: value.width,
+            line_cap: match value.cap {
+                LineCap::Butt => tiny_skia::LineCap::Butt,
+                LineCap::Round => tiny_skia::LineCap::Round,
+            },
+            line_join: match value.join {
+                LineJoin::Miter => tiny_skia::LineJoin::Miter,
+                LineJoin::Round => tiny_skia::LineJoin::Round,
+                LineJoin::Bevel => tiny_skia::LineJoin::Bevel,
+            },
+            dash: value
+                .dash
+                .as_ref()
+                .and_then(|dash| StrokeDash::new(dash.clone(), 0.0)),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&LinePaint> for Paint<'_> {
+    fn from(value: &LinePaint) -> Self {
+        let mut paint = Paint::default();
+        match *value {
+            LinePaint::Solid(color) => paint.set_color(color.into()),
+            LinePaint::Gradient {
+                start,
+                end,
+                start_color,
+                end_color,
+            } => {
+                let gradient = LinearGradient::new(
+                    tiny_skia::Point::from_xy(start.0, start.1),
+                    tiny_skia::Point::from_xy(end.0, end.1),
+                    vec![
+                        GradientStop::new(0.0, start_color.into()),
+                        GradientStop::new(1.0, end_color.into()),
+                    ],
+                    SpreadMode::Pad,
+                    Transform::identity(),
+                );
+                paint.shader = gradient.unwrap_or(Shader::SolidColor(start_color.into()));
+            }
+        }
+        paint
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt::Write;
+
+use rusttype::OutlineBuilder;
+
+use crate::{options::Color, pattern_utils::HexCoord};
+
+use super::{layout_text, LineCap, LineJoin, LinePaint, LineStyle, RenderTarget, TextFont};
+
+///Render target that builds up an svg document
+/// Use [SvgCanvas::finish] to get the resulting document
+pub struct SvgCanvas {
+    width: f32,
+    height: f32,
+    defs: String,
+    body: String,
+    gradient_count: usize,
+    filter_count: usize,
+}
+
+impl SvgCanvas {
+    ///Creates an empty svg of the given size (in pixels)
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            defs: String::new(),
+            body: String::new(),
+            gradient_count: 0,
+            filter_count: 0,
+        }
+    }
+
+    ///Finishes the document and returns it as a string
+    pub fn finish(self) -> String {
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            num(self.width),
+            num(self.height)
+        );
+        if !self.defs.is_empty() {
+            let _ = writeln!(svg, "<defs>\n{}</defs>", self.defs);
+        }
+        svg.push_str(&self.body);
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn add_gradient(&mut self, start: HexCoord, end: HexCoord, start_color: Color, end_color: Color) -> String {
+        let id = format!("g{}", self.gradient_count);
+        self.gradient_count += 1;
+
+        let _ = writeln!(
+            self.defs,
+            r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}"><stop offset="0" {}/><stop offset="1" {}/></linearGradient>"#,
+            num(start.0),
+            num(start.1),
+            num(end.0),
+            num(end.1),
+            color_attrs("stop-color", "stop-opacity", start_color),
+            color_attrs("stop-color", "stop-opacity", end_color),
+        );
+        id
+    }
+}
+
+impl RenderTarget for SvgCanvas {
+    fn stroke_path(&mut self, points: &[HexCoord], stroke: &LineStyle, paint: &LinePaint) {
+        if points.len() < 2 {
+            return;
+        }
+        let paint = match *paint {
+            LinePaint::Solid(color) => color_attrs("stroke", "stroke-opacity", color),
+            LinePaint::Gradient {
+                start,
+                end,
+                start_color,
+                end_color,
+            } => {
+                let id = self.add_gradient(start, end, start_color, end_color);
+                format!(r#"stroke="url(#{id})""#)
+            }
+        };
+        let cap = match stroke.cap {
+            LineCap::Butt => "butt",
+            LineCap::Round => "round",
+        };
+        let join = match stroke.join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        };
+        let dash = match &stroke.dash {
+            Some(dash) => format!(
+                r#" stroke-dasharray="{}""#,
+                dash.iter().map(|len| num(*len)).collect::<Vec<_>>().join(" ")
+            ),
+            None => String::new(),
+        };
+        let _ = writeln!(
+            self.body,
+            r#"<polyline points="{}" fill="none" {paint} stroke-width="{}" stroke-linecap="{cap}" stroke-linejoin="{join}"{dash}/>"#,
+            points_attr(points),
+            num(stroke.width),
+        );
+    }
+
+    fn fill_circle(&mut self, center: HexCoord, radius: f32, color: Color) {
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{}" cy="{}" r="{}" {}/>"#,
+            num(center.0),
+            num(center.1),
+            num(radius),
+            color_attrs("fill", "fill-opacity", color)
+        );
+    }
+
+    fn fill_polygon(&mut self, points: &[HexCoord], color: Color) {
+        let _ = writeln!(
+            self.body,
+            r#"<polygon points="{}" {}/>"#,
+            points_attr(points),
+            color_attrs("fill", "fill-opacity", color)
+        );
+    }
+
+    fn draw_text(&mut self, text: &str, center: HexCoord, size: f32, font: TextFont, color: Color) {
+        let (glyphs, width, height) = layout_text(text, size, font);
+        let offset = center - HexCoord(width, height) / 2.0;
+
+        let mut path = String::new();
+        for g in glyphs {
+            if let Some(bb) = g.pixel_bounding_box() {
+                let mut builder = OffsetPath {
+                    path: &mut path,
+                    offset: offset + HexCoord(bb.min.x as f32, bb.min.y as f32),
+                };
+                g.build_outline(&mut builder);
+            }
+        }
+        if path.is_empty() {
+            return;
+        }
+        let _ = writeln!(
+            self.body,
+            r#"<path d="{}" {}/>"#,
+            path.trim_end(),
+            color_attrs("fill", "fill-opacity", color)
+        );
+    }
+
+    fn draw_blurred(&mut self, radius: f32, strength: f32, draw: &mut dyn FnMut(&mut dyn RenderTarget)) {
+        let id = format!("b{}", self.filter_count);
+        self.filter_count += 1;
+
+        //the standard deviation is half the radius (the same as the pixmap blur)
+        let _ = writeln!(
+            self.defs,
+            r#"<filter id="{id}" filterUnits="userSpaceOnUse" x="0" y="0" width="{}" height="{}"><feGaussianBlur stdDeviation="{}"/><feComponentTransfer><feFuncA type="linear" slope="{}"/></feComponentTransfer></filter>"#,
+            num(self.width),
+            num(self.height),
+            num(radius / 2.0),
+            num(strength),
+        );
+
+        let _ = writeln!(self.body, r#"<g filter="url(#{id})">"#);
+        draw(self);
+        self.body.push_str("</g>\n");
+    }
+}
+
+struct OffsetPath<'a> {
+    path: &'a mut String,
+    offset: HexCoord,
+}
+
+impl OutlineBuilder for OffsetPath<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.path, "M{} {} ", num(x + self.offset.0), num(y + self.offset.1));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.path, "L{} {} ", num(x + self.offset.0), num(y + self.offset.1));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let _ = write!(
+            self.path,
+            "Q{} {} {} {} ",
+            num(x1 + self.offset.0),
+            num(y1 + self.offset.1),
+            num(x + self.offset.0),
+            num(y + self.offset.1)
+        );
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let _ = write!(
+            self.path,
+            "C{} {} {} {} {} {} ",
+            num(x1 + self.offset.0),
+            num(y1 + self.offset.1),
+            num(x2 + self.offset.0),
+            num(y2 + self.offset.1),
+            num(x + self.offset.0),
+            num(y + self.offset.1)
+        );
+    }
+
+    fn close(&mut self) {
+        self.path.push_str("Z ");
+    }
+}
+
+///Formats a number with at most 2 decimal places
+fn num(val: f32) -> String {
+    let str = format!("{val:.2}");
+    let str = str.trim_end_matches('0').trim_end_matches('.');
+    if str == "-0" {
+        "0".to_string()
+    } else {
+        str.to_string()
+    }
+}
+
+fn points_attr(points: &[HexCoord]) -> String {
+    points
+        .iter()
+        .map(|point| format!("{},{}", num(point.0), num(point.1)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn color_attrs(color_attr: &str, opacity_attr: &str, color: Color) -> String {
+    let mut attrs = format!(
+        r##"{color_attr}="#{:02x}{:02x}{:02x}""##,
+        color.0, color.1, color.2
+    );
+    if color.3 != 255 {
+        let _ = write!(attrs, r#" {opacity_attr}="{}""#, num(color.3 as f32 / 255.0));
+    }
+    attrs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grids::{GridDraw, HexGrid},
+        options::{GridOptions, GridPatternOptions, Intersections, Lines, Point},
+        Pattern,
+    };
+
+    use super::*;
+
+    ///Checks that every tag is closed in the right order and returns the tag names in order
+    fn tags(svg: &str) -> Vec<String> {
+        let mut open: Vec<String> = Vec::new();
+        let mut names = Vec::new();
+
+        for tag in svg.split('<').skip(1) {
+            let tag = &tag[..tag.find('>').expect("unclosed tag")];
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop().as_deref(), Some(name));
+                continue;
+            }
+
+            let name = tag.split_whitespace().next().unwrap().to_string();
+            if !tag.ends_with('/') {
+                open.push(name.clone());
+            }
+            names.push(name);
+        }
+
+        assert!(open.is_empty(), "{open:?}");
+        names
+    }
+
+    #[test]
+    fn grid_svg() {
+        let patterns = ["EAST aawdd", "NORTH_EAST qaq", "WEST qqqqqaweeeee"]
+            .into_iter()
+            .map(|pattern| Pattern::try_from(pattern).unwrap())
+            .collect();
+        let grid = HexGrid::new_normal(patterns, 10).unwrap();
+        let options = GridOptions::generate(
+            GridPatternOptions::Uniform(
+                Intersections::Nothing,
+                Lines::Monocolor {
+                    color: Color(255, 0, 0, 255),
+                    bent: true,
+                },
+            ),
+            Point::None,
+        );
+
+        let svg = grid.draw_grid_svg(30.0, &options).unwrap();
+
+        let padding = grid.get_max_radius(&options) * 1.1;
+        let (width, height) = grid.get_padded_size(30.0, padding);
+        assert!(
+            svg.starts_with(&format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+                num(width.floor()),
+                num(height.floor())
+            )),
+            "{svg}"
+        );
+        assert!(svg.ends_with("</svg>\n"));
+
+        let tags = tags(&svg);
+        assert_eq!(tags[0], "svg");
+        //one stroked line per pattern and nothing else
+        assert_eq!(&tags[1..], ["polyline", "polyline", "polyline"]);
+        assert_eq!(svg.matches(r##"stroke="#ff0000""##).count(), 3);
+    }
+
+    #[test]
+    fn glow_and_gradients_svg() {
+        let patterns = vec![Pattern::try_from("EAST aawdd").unwrap(); 2];
+        let grid = HexGrid::new_normal(patterns, 10)
+            .unwrap()
+            .with_glow(&crate::defaults::components::GLOW);
+
+        let svg = grid.draw_grid_svg(30.0, &crate::defaults::POINT_GRADIENT).unwrap();
+
+        let tags = tags(&svg);
+        assert_eq!(tags.iter().filter(|tag| *tag == "filter").count(), 1);
+        assert!(tags.iter().any(|tag| tag == "linearGradient"));
+        assert!(tags.iter().any(|tag| tag == "g"));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(num(1.0), "1");
+        assert_eq!(num(1.5), "1.5");
+        assert_eq!(num(2.456), "2.46");
+        assert_eq!(num(-0.001), "0");
+        assert_eq!(color_attrs("fill", "fill-opacity", Color(255, 0, 16, 128)), r##"fill="#ff0010" fill-opacity="0.5""##);
+    }
+}
@@ -0,0 +1,187 @@
+//! Serialize/Deserialize for the types that have a more readable form as text
+//! (the rest of the options just derive them)
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    options::Color,
+    pattern_utils::{Angle, Direction},
+    Pattern, PatternFormat,
+};
+
+///Written as #RRGGBBAA (anything [Color::from_str](std::str::FromStr::from_str) accepts is read)
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Color(r, g, b, a) = self;
+        serializer.serialize_str(&format!("#{r:02X}{g:02X}{b:02X}{a:02X}"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let str = String::deserialize(deserializer)?;
+        str.parse().map_err(|_| D::Error::custom(format!("invalid color {str:?}")))
+    }
+}
+
+///Written as its letter (eg. q)
+impl Serialize for Angle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char((*self).into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Angle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let char = char::deserialize(deserializer)?;
+        Angle::try_from(char).map_err(|_| D::Error::custom(format!("invalid angle {char:?}")))
+    }
+}
+
+///Written the same way as ingame (eg. NORTH_EAST)
+impl Serialize for Direction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str((*self).into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let str = String::deserialize(deserializer)?;
+        Direction::try_from(&str[..]).map_err(|_| D::Error::custom(format!("invalid direction {str:?}")))
+    }
+}
+
+///Written as the start direction and angle_sig (eg. EAST qaq)
+/// Anything [Pattern::try_from] accepts is read (so HexPattern(EAST qaq) works too)
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.display(PatternFormat::Short))
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let str = String::deserialize(deserializer)?;
+        Pattern::try_from(&str[..]).map_err(|_| D::Error::custom(format!("invalid pattern {str:?}")))
+    }
+}
+
+///Writes lists of angle_sigs as strings (eg. ["qqq"]) instead of lists of letters
+pub(crate) mod angle_sigs {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(sigs: &[Vec<Angle>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            sigs.iter()
+                .map(|sig| sig.iter().map(|angle| char::from(*angle)).collect::<String>()),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<Angle>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|sig| {
+                sig.chars()
+                    .map(Angle::try_from)
+                    .collect::<Result<Vec<Angle>, _>>()
+                    .map_err(|err| D::Error::custom(format!("invalid angle {:?} in {sig:?}", err.0)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::options::{ColorSpace, Easing, GlowOptions, GridOptions, GridPatternOptions, Intersections, Lines, Point};
+
+    use super::*;
+
+    #[test]
+    fn color() {
+        let color = Color(255, 0, 16, 200);
+        let json = serde_json::to_string(&color).unwrap();
+
+        assert_eq!(json, r##""#FF0010C8""##);
+        assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), color);
+        //anything FromStr accepts is read
+        assert_eq!(serde_json::from_str::<Color>(r##""#f01""##).unwrap(), Color(255, 0, 17, 255));
+        assert!(serde_json::from_str::<Color>(r#""not a color""#).is_err());
+    }
+
+    #[test]
+    fn pattern() {
+        let pattern = Pattern::try_from("NORTH_EAST qaq").unwrap();
+        let json = serde_json::to_string(&pattern).unwrap();
+
+        assert_eq!(json, r#""NORTH_EAST qaq""#);
+        assert_eq!(serde_json::from_str::<Pattern>(&json).unwrap(), pattern);
+        assert_eq!(
+            serde_json::from_str::<Pattern>(r#""HexPattern(NORTH_EAST qaq)""#).unwrap(),
+            pattern
+        );
+        assert!(serde_json::from_str::<Pattern>(r#""NORTH_EAST qxq""#).is_err());
+    }
+
+    #[test]
+    fn grid_options() {
+        let options = GridOptions::generate(
+            GridPatternOptions::generate_default_changing(
+                Intersections::Nothing,
+                vec![Lines::Monocolor {
+                    color: Color::WHITE,
+                    bent: true,
+                }],
+            ),
+            Point::None,
+        );
+        let json = serde_json::to_string(&options).unwrap();
+
+        assert!(json.contains(r#""intros":["qqq"]"#), "{json}");
+        assert_eq!(serde_json::from_str::<GridOptions>(&json).unwrap(), options);
+    }
+
+    #[test]
+    fn grid_options_defaults() {
+        //written before gradients had a color space and easing
+        let json = r##"{
+            "line_thickness": 0.12,
+            "pattern_options": {"Uniform": ["Nothing", {"Gradient": {
+                "colors": ["#FF0000FF", "#0000FFFF"],
+                "segments_per_color": 15,
+                "bent": true
+            }}]},
+            "center_dot": "None"
+        }"##;
+
+        let options = serde_json::from_str::<GridOptions>(json).unwrap();
+        assert_eq!(
+            options,
+            GridOptions {
+                line_thickness: 0.12,
+                pattern_options: GridPatternOptions::Uniform(
+                    Intersections::Nothing,
+                    Lines::Gradient {
+                        colors: vec![Color(255, 0, 0, 255), Color(0, 0, 255, 255)],
+                        segments_per_color: 15,
+                        bent: true,
+                        space: ColorSpace::Srgb,
+                        easing: Easing::Linear,
+                    }
+                ),
+                center_dot: Point::None,
+            }
+        );
+    }
+
+    #[test]
+    fn glow_options() {
+        let glow = GlowOptions {
+            radius: 0.3,
+            strength: 1.5,
+            points: false,
+        };
+        let json = serde_json::to_string(&glow).unwrap();
+
+        assert_eq!(serde_json::from_str::<GlowOptions>(&json).unwrap(), glow);
+    }
+}